@@ -0,0 +1,256 @@
+//! 整数論の補助関数
+
+use num_bigint::{BigInt, BigUint};
+use num_integer::Integer;
+use num_traits::{One, Zero};
+
+/// 試し割りに使う小さな素数
+const SMALL_PRIMES: [u32; 25] = [
+    2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+];
+
+/// Miller–Rabin法による素数判定
+///
+/// 最初の25個の素数を底に用いるため、実用上の誤判定はない
+/// (3.3·10²⁴ 未満では決定的)。
+pub(crate) fn is_probable_prime(n: &BigUint) -> bool {
+    if *n < BigUint::from(2u32) {
+        return false;
+    }
+    for &p in SMALL_PRIMES.iter() {
+        if *n == BigUint::from(p) {
+            return true;
+        }
+        if (n % p).is_zero() {
+            return false;
+        }
+    }
+
+    // n - 1 = d·2^s
+    let n_minus_one = n - 1u32;
+    let s = n_minus_one.trailing_zeros().unwrap_or(0);
+    let d = &n_minus_one >> s;
+
+    'witness: for &a in SMALL_PRIMES.iter() {
+        let mut x = BigUint::from(a).modpow(&d, n);
+        if x.is_one() || x == n_minus_one {
+            continue;
+        }
+        for _ in 1..s {
+            x = &x * &x % n;
+            if x == n_minus_one {
+                continue 'witness;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+/// 素因数分解を行い、(素因数, 指数) の組を昇順で返す
+///
+/// 小さな素数で試し割りした後、残りをPollardのρ法で分解する。
+pub(crate) fn factor(n: &BigUint) -> Vec<(BigUint, u32)> {
+    let mut primes = Vec::new();
+    let mut remaining = n.clone();
+
+    for &p in SMALL_PRIMES.iter() {
+        while !remaining.is_zero() && (&remaining % p).is_zero() {
+            primes.push(BigUint::from(p));
+            remaining /= p;
+        }
+    }
+
+    let mut stack = vec![remaining];
+    while let Some(m) = stack.pop() {
+        if m <= BigUint::one() {
+            continue;
+        }
+        if is_probable_prime(&m) {
+            primes.push(m);
+            continue;
+        }
+        let d = pollard_rho(&m);
+        stack.push(&m / &d);
+        stack.push(d);
+    }
+
+    primes.sort();
+    let mut factors: Vec<(BigUint, u32)> = Vec::new();
+    for p in primes {
+        match factors.last_mut() {
+            Some((last, e)) if *last == p => *e += 1,
+            _ => factors.push((p, 1)),
+        }
+    }
+    factors
+}
+
+/// 試し割りで取り除く素因数の上限
+const TRIAL_DIVISION_BOUND: u32 = 1 << 16;
+
+/// ρ法で分解を試みる合成数のビット長の上限
+const RHO_BITS_LIMIT: u64 = 80;
+
+/// 最大の素因数を求める
+///
+/// 2¹⁶ までの試し割りの後に残った合成数が大きすぎる場合は、
+/// ρ法では現実的な時間で分解できないので `None` を返す。
+pub(crate) fn largest_prime_factor(n: &BigUint) -> Option<BigUint> {
+    let mut remaining = n.clone();
+    let mut largest = None;
+    for d in 2..TRIAL_DIVISION_BOUND {
+        if remaining.is_one() {
+            break;
+        }
+        if (&remaining % d).is_zero() {
+            while (&remaining % d).is_zero() {
+                remaining /= d;
+            }
+            largest = Some(BigUint::from(d));
+        }
+    }
+
+    if remaining.is_one() {
+        largest
+    } else if is_probable_prime(&remaining) || remaining.bits() <= RHO_BITS_LIMIT {
+        factor(&remaining).pop().map(|(q, _)| q)
+    } else {
+        None
+    }
+}
+
+/// 中国剰余定理: x ≡ a (mod m), x ≡ b (mod n) (gcd(m, n) = 1) となる x mod mn
+///
+/// m の逆元は拡張ユークリッド互除法で求めるので、法は素数でなくてよい。
+pub(crate) fn crt(a: &BigUint, m: &BigUint, b: &BigUint, n: &BigUint) -> BigUint {
+    let m_int = BigInt::from(m.clone());
+    let n_int = BigInt::from(n.clone());
+    let inv = m_int.extended_gcd(&n_int).x;
+    let diff = BigInt::from(b.clone()) - BigInt::from(a.clone());
+    let k = (diff * inv).mod_floor(&n_int);
+    (BigInt::from(a.clone()) + k * m_int).magnitude().clone()
+}
+
+/// Pollardのρ法 (Brentの改良版) で合成数 n の非自明な約数を探す
+fn pollard_rho(n: &BigUint) -> BigUint {
+    let one = BigUint::one();
+    let mut c = BigUint::one();
+
+    loop {
+        // f(x) = x² + c
+        let f = |x: &BigUint| (x * x + &c) % n;
+        let mut y = BigUint::from(2u32);
+        let mut r: u64 = 1;
+        let mut q = BigUint::one();
+        let mut g = BigUint::one();
+        let mut x = y.clone();
+        let mut ys = y.clone();
+
+        while g == one {
+            x = y.clone();
+            for _ in 0..r {
+                y = f(&y);
+            }
+            let mut k = 0;
+            while k < r && g == one {
+                ys = y.clone();
+                for _ in 0..r.min(128).min(r - k) {
+                    y = f(&y);
+                    let diff = if x > y { &x - &y } else { &y - &x };
+                    q = q * diff % n;
+                }
+                g = q.gcd(n);
+                k += 128;
+            }
+            r *= 2;
+        }
+
+        if g == *n {
+            // まとめて計算した分で失敗した場合は1ステップずつやり直す
+            loop {
+                ys = f(&ys);
+                let diff = if x > ys { &x - &ys } else { &ys - &x };
+                g = diff.gcd(n);
+                if g != one {
+                    break;
+                }
+            }
+        }
+
+        if g != *n {
+            return g;
+        }
+        c += 1u32;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_probable_prime() {
+        let primes: Vec<u32> = (0..200u32)
+            .filter(|&n| is_probable_prime(&BigUint::from(n)))
+            .collect();
+        let expected: Vec<u32> = (2..200u32)
+            .filter(|&n| (2..n).all(|d| n % d != 0))
+            .collect();
+        assert_eq!(primes, expected);
+
+        // カーマイケル数と大きな素数
+        assert!(!is_probable_prime(&BigUint::from(561u32)));
+        assert!(!is_probable_prime(&BigUint::from(3_215_031_751u64)));
+        assert!(is_probable_prime(&((BigUint::one() << 127) - 1u32)));
+    }
+
+    #[test]
+    fn test_largest_prime_factor() {
+        assert_eq!(
+            largest_prime_factor(&BigUint::from(252u32)),
+            Some(BigUint::from(7u32))
+        );
+        assert_eq!(largest_prime_factor(&BigUint::one()), None);
+
+        // 2⁶¹ - 1 は素数
+        let mersenne = (BigUint::one() << 61u32) - 1u32;
+        assert_eq!(
+            largest_prime_factor(&(&mersenne * 6u32)),
+            Some(mersenne.clone())
+        );
+
+        // 大きな素因数を2つ持つ数は分解しない
+        assert_eq!(largest_prime_factor(&(&mersenne * &mersenne)), None);
+    }
+
+    #[test]
+    fn test_crt() {
+        // 合成数の法 (9 と 8) でも正しく復元する
+        let (m, n) = (BigUint::from(9u32), BigUint::from(8u32));
+        for x in 0..72u32 {
+            let x = BigUint::from(x);
+            assert_eq!(crt(&(&x % &m), &m, &(&x % &n), &n), x);
+        }
+    }
+
+    #[test]
+    fn test_factor() {
+        let factors = |n: u64| -> Vec<(u64, u32)> {
+            factor(&BigUint::from(n))
+                .into_iter()
+                .map(|(p, e)| (p.to_u64_digits()[0], e))
+                .collect()
+        };
+        assert_eq!(factors(1), vec![]);
+        assert_eq!(factors(232), vec![(2, 3), (29, 1)]);
+        assert_eq!(
+            factors(1_000_003 * 1_000_033),
+            vec![(1_000_003, 1), (1_000_033, 1)]
+        );
+        assert_eq!(
+            factors(101u64.pow(2) * 4_294_967_291),
+            vec![(101, 2), (4_294_967_291, 1)]
+        );
+    }
+}
@@ -0,0 +1,440 @@
+use num_bigint::{BigInt, BigUint};
+use std::ops::{Add, Mul, Neg};
+
+use super::CurveError;
+use crate::field::FieldElement;
+use crate::point::{double_and_add, ladder, CurvePoint, PointError};
+
+/// ツイストEdwards曲線 ax² + y² = 1 + dx²y²
+///
+/// a が平方剰余で d が平方非剰余のとき、統一加算公式はすべての点の組で定義される (完全)。
+#[derive(Debug, Clone, PartialEq)]
+pub struct EdwardsCurve {
+    pub a: FieldElement,
+    pub d: FieldElement,
+    pub prime: BigUint,
+}
+
+/// 拡張座標 (X : Y : Z : T) で表したツイストEdwards曲線上の点
+///
+/// アフィン座標とは x = X/Z, y = Y/Z, xy = T/Z で対応する。
+#[derive(Debug, Clone)]
+pub struct EdwardsPoint {
+    x: FieldElement,
+    y: FieldElement,
+    z: FieldElement,
+    t: FieldElement,
+    pub curve: EdwardsCurve,
+}
+
+/// 曲線の定義
+impl EdwardsCurve {
+    pub fn new(
+        a: impl Into<BigInt>,
+        d: impl Into<BigInt>,
+        prime: impl Into<BigInt>,
+    ) -> Result<Self, CurveError> {
+        let prime: BigInt = prime.into();
+        let a = FieldElement::new(a, prime.clone()).map_err(|_| CurveError::InvalidParameters)?;
+        let d = FieldElement::new(d, prime.clone()).map_err(|_| CurveError::InvalidParameters)?;
+
+        // 標数2では定義しない。a, d ≠ 0 かつ a ≠ d でなければ特異になる
+        if !prime.bit(0) || a.is_zero() || d.is_zero() || a == d {
+            return Err(CurveError::InvalidParameters);
+        }
+        Ok(Self {
+            a,
+            d,
+            prime: prime.magnitude().clone(),
+        })
+    }
+
+    /// Ed25519 の曲線 -x² + y² = 1 - (121665/121666)x²y² over F(2²⁵⁵ - 19)
+    pub fn ed25519() -> Self {
+        let prime: BigInt = (BigInt::from(1) << 255u32) - 19;
+        let element = |value: i64| FieldElement::new(value, prime.clone()).expect("prime is valid");
+        let d = -(element(121665) / element(121666));
+        Self::new(-1, d.value().clone(), prime).expect("Ed25519 parameters are valid")
+    }
+
+    /// 統一加算公式が完全かどうか (a が平方剰余かつ d が平方非剰余)
+    pub fn is_complete(&self) -> bool {
+        self.a.sqrt().is_some() && self.d.sqrt().is_none()
+    }
+
+    /// 指定された座標にある点を生成
+    pub fn point(
+        &self,
+        x: impl Into<BigInt>,
+        y: impl Into<BigInt>,
+    ) -> Result<EdwardsPoint, CurveError> {
+        let x = FieldElement::new(x, self.prime.clone())
+            .map_err(|_| CurveError::PointGenerationFailed)?;
+        let y = FieldElement::new(y, self.prime.clone())
+            .map_err(|_| CurveError::PointGenerationFailed)?;
+        EdwardsPoint::new(x, y, self.clone()).map_err(|_| CurveError::PointGenerationFailed)
+    }
+
+    /// 単位元 (0, 1) を生成
+    pub fn identity(&self) -> EdwardsPoint {
+        EdwardsPoint::from_affine(self.constant(0), self.constant(1), self.clone())
+    }
+
+    /// y 座標と x の偶奇から点を復元
+    ///
+    /// x² = (y² - 1) / (dy² - a) の平方根のうち、偶奇が一致するものを選ぶ。
+    pub fn point_from_y(
+        &self,
+        y: impl Into<BigInt>,
+        x_is_odd: bool,
+    ) -> Result<EdwardsPoint, CurveError> {
+        let y = FieldElement::new(y, self.prime.clone())
+            .map_err(|_| CurveError::PointGenerationFailed)?;
+        let y2 = &y * &y;
+        let x2 = (&y2 - self.constant(1))
+            .checked_div(&(&self.d * &y2 - &self.a))
+            .map_err(|_| CurveError::InvalidXCoordinate)?;
+        let (x1, x2) = x2.sqrt().ok_or(CurveError::InvalidXCoordinate)?;
+        let x = if x1.value().bit(0) == x_is_odd {
+            x1
+        } else if x2.value().bit(0) == x_is_odd {
+            x2
+        } else {
+            // x = 0 のときは偶数の根しか存在しない
+            return Err(CurveError::InvalidXCoordinate);
+        };
+        Ok(EdwardsPoint::from_affine(x, y, self.clone()))
+    }
+
+    /// 点のエンコードのバイト長 (y 座標と符号ビットが入る長さ)
+    pub fn encoded_len(&self) -> usize {
+        (self.prime.bits() + 1).div_ceil(8) as usize
+    }
+
+    /// RFC 8032 形式 (y のリトルエンディアン、最上位ビットに x の偶奇) からデコード
+    pub fn decode_point(&self, bytes: &[u8]) -> Result<EdwardsPoint, CurveError> {
+        if bytes.len() != self.encoded_len() {
+            return Err(CurveError::InvalidEncoding);
+        }
+        let mut bytes = bytes.to_vec();
+        let last = bytes.last_mut().ok_or(CurveError::InvalidEncoding)?;
+        let x_is_odd = *last & 0x80 != 0;
+        *last &= 0x7F;
+
+        let y = BigUint::from_bytes_le(&bytes);
+        if y >= self.prime {
+            return Err(CurveError::InvalidEncoding);
+        }
+        self.point_from_y(y, x_is_odd)
+            .map_err(|_| CurveError::InvalidEncoding)
+    }
+
+    /// 定数を体の元として生成
+    fn constant(&self, value: i64) -> FieldElement {
+        FieldElement::new(value, self.prime.clone()).expect("prime is valid")
+    }
+}
+
+/// 点の生成と操作を定義
+impl EdwardsPoint {
+    /// アフィン座標 (x, y) から点を生成
+    pub fn new(x: FieldElement, y: FieldElement, curve: EdwardsCurve) -> Result<Self, PointError> {
+        if x.prime() != &curve.prime || y.prime() != &curve.prime {
+            return Err(PointError::NotOnCurve);
+        }
+        // ax² + y² = 1 + dx²y² の確認
+        let x2 = &x * &x;
+        let y2 = &y * &y;
+        if &curve.a * &x2 + &y2 != curve.constant(1) + &curve.d * &x2 * &y2 {
+            return Err(PointError::NotOnCurve);
+        }
+        Ok(Self::from_affine(x, y, curve))
+    }
+
+    /// 曲線上にあることが分かっているアフィン座標から生成
+    fn from_affine(x: FieldElement, y: FieldElement, curve: EdwardsCurve) -> Self {
+        Self {
+            t: &x * &y,
+            z: curve.constant(1),
+            x,
+            y,
+            curve,
+        }
+    }
+
+    /// アフィン座標 (x, y) に変換
+    pub fn to_affine(&self) -> (FieldElement, FieldElement) {
+        // 加算で Z = FG ≠ 0 を保証しているので逆元は存在する
+        let z_inv = self.z.try_inv().expect("Z is never zero");
+        (&self.x * &z_inv, &self.y * &z_inv)
+    }
+
+    pub fn is_identity(&self) -> bool {
+        self.x.is_zero() && self.y == self.z
+    }
+
+    /// RFC 8032 形式にエンコード
+    pub fn encode(&self) -> Vec<u8> {
+        let (x, y) = self.to_affine();
+        let mut bytes = y.value().to_bytes_le();
+        bytes.resize(self.curve.encoded_len(), 0);
+        if x.value().bit(0) {
+            *bytes.last_mut().expect("encoding is not empty") |= 0x80;
+        }
+        bytes
+    }
+
+    /// 公開スカラーによる可変時間のスカラー倍算（double-and-add）
+    pub fn mul_vartime(&self, scalar: impl Into<BigInt>) -> Result<Self, PointError> {
+        double_and_add(self, self.curve.identity(), &scalar.into())
+    }
+
+    /// Montgomeryラダーによるスカラー倍算
+    ///
+    /// 加算公式が統一されているので、2倍算も同じ加算で行える。
+    fn mul_ladder(&self, scalar: &BigInt) -> Result<Self, PointError> {
+        ladder(
+            self.clone(),
+            self.curve.identity(),
+            scalar,
+            &self.curve.prime,
+            |point| point + point,
+        )
+    }
+}
+
+/// 射影座標での比較 (X₁Z₂ = X₂Z₁ かつ Y₁Z₂ = Y₂Z₁)
+impl PartialEq for EdwardsPoint {
+    fn eq(&self, other: &Self) -> bool {
+        self.curve == other.curve
+            && &self.x * &other.z == &other.x * &self.z
+            && &self.y * &other.z == &other.y * &self.z
+    }
+}
+
+/// 拡張座標での統一加算 (Hisil–Wong–Carter–Dawson, add-2008-hwcd)
+impl Add<&EdwardsPoint> for &EdwardsPoint {
+    type Output = Result<EdwardsPoint, PointError>;
+
+    fn add(self, other: &EdwardsPoint) -> Result<EdwardsPoint, PointError> {
+        if self.curve != other.curve {
+            return Err(PointError::DifferentCurves);
+        }
+        let curve = &self.curve;
+
+        let a = &self.x * &other.x;
+        let b = &self.y * &other.y;
+        let c = &curve.d * &self.t * &other.t;
+        let d = &self.z * &other.z;
+        let e = (&self.x + &self.y) * (&other.x + &other.y) - &a - &b;
+        let f = &d - &c;
+        let g = &d + &c;
+        let h = &b - &curve.a * &a;
+
+        // F, G = Z₁Z₂(1 ∓ dx₁x₂y₁y₂) が0になるのは完全でない曲線の例外的な組だけ
+        if f.is_zero() || g.is_zero() {
+            return Err(PointError::IncompleteAddition);
+        }
+        Ok(EdwardsPoint {
+            x: &e * &f,
+            y: &g * &h,
+            z: &f * &g,
+            t: &e * &h,
+            curve: curve.clone(),
+        })
+    }
+}
+
+impl Add for EdwardsPoint {
+    type Output = Result<Self, PointError>;
+
+    fn add(self, other: Self) -> Result<Self, PointError> {
+        &self + &other
+    }
+}
+
+/// 逆元 -(x, y) = (-x, y)
+impl Neg for EdwardsPoint {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            x: -self.x,
+            t: -self.t,
+            ..self
+        }
+    }
+}
+
+/// 秘密スカラーを想定し、Montgomeryラダーで計算する
+impl Mul<&BigInt> for EdwardsPoint {
+    type Output = Result<Self, PointError>;
+
+    fn mul(self, scalar: &BigInt) -> Result<Self, PointError> {
+        self.mul_ladder(scalar)
+    }
+}
+
+/// 多倍長の非負整数のスカラー乗算を定義
+impl Mul<&BigUint> for EdwardsPoint {
+    type Output = Result<Self, PointError>;
+
+    fn mul(self, scalar: &BigUint) -> Result<Self, PointError> {
+        self.mul_ladder(&BigInt::from(scalar.clone()))
+    }
+}
+
+/// ツイストEdwards形式の点
+impl CurvePoint for EdwardsPoint {
+    fn identity(&self) -> Self {
+        self.curve.identity()
+    }
+
+    fn is_identity(&self) -> bool {
+        EdwardsPoint::is_identity(self)
+    }
+
+    fn is_on_same_curve(&self, other: &Self) -> bool {
+        self.curve == other.curve
+    }
+
+    fn add_point(&self, other: &Self) -> Result<Self, PointError> {
+        self + other
+    }
+
+    fn negate(&self) -> Self {
+        -self.clone()
+    }
+
+    fn mul_secret(&self, scalar: &BigUint) -> Result<Self, PointError> {
+        self.clone() * scalar
+    }
+
+    fn mul_public(&self, scalar: &BigUint) -> Result<Self, PointError> {
+        self.mul_vartime(scalar.clone())
+    }
+
+    /// RFC 8032 形式
+    fn to_bytes(&self) -> Vec<u8> {
+        self.encode()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocols::ed25519;
+
+    /// 曲線上の点をすべて列挙する
+    fn all_points(curve: &EdwardsCurve) -> Vec<EdwardsPoint> {
+        let p = curve.prime.to_u64_digits()[0];
+        (0..p)
+            .flat_map(|x| (0..p).map(move |y| (x, y)))
+            .filter_map(|(x, y)| curve.point(x, y).ok())
+            .collect()
+    }
+
+    #[test]
+    fn test_group_law_complete_curve() {
+        // x² + y² = 1 + 2x²y² over F₁₃ (d = 2 は平方非剰余)
+        let curve = EdwardsCurve::new(1, 2, 13).unwrap();
+        assert!(curve.is_complete());
+        let points = all_points(&curve);
+        assert_eq!(points.len(), 8);
+
+        let identity = curve.identity();
+        for p in &points {
+            assert_eq!((p + &identity).unwrap(), *p);
+            assert!((p + &-p.clone()).unwrap().is_identity());
+            assert!(p.mul_vartime(8).unwrap().is_identity());
+            for q in &points {
+                let pq = (p + q).unwrap();
+                assert_eq!(pq, (q + p).unwrap());
+                // 結果はアフィン座標に戻しても曲線上にある
+                let (x, y) = pq.to_affine();
+                assert!(EdwardsPoint::new(x, y, curve.clone()).is_ok());
+                for r in &points {
+                    assert_eq!((&pq + r).unwrap(), (p + &(q + r).unwrap()).unwrap());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_incomplete_curve_exceptions() {
+        // d = 3 は F₁₃ の平方剰余なので、dx₁x₂y₁y₂ = ±1 となる組で加算できない
+        let curve = EdwardsCurve::new(1, 3, 13).unwrap();
+        assert!(!curve.is_complete());
+        let points = all_points(&curve);
+        let p = curve.point(4, 6).unwrap();
+        assert!(matches!(&p + &p, Err(PointError::IncompleteAddition)));
+        assert!(points.iter().any(|q| (&p + q).is_ok()));
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert!(EdwardsCurve::new(0, 2, 13).is_err());
+        assert!(EdwardsCurve::new(1, 0, 13).is_err());
+        assert!(EdwardsCurve::new(2, 2, 13).is_err());
+        assert!(EdwardsCurve::new(1, 2, 16).is_err());
+        let curve = EdwardsCurve::new(1, 2, 13).unwrap();
+        assert!(curve.point(2, 2).is_err());
+    }
+
+    #[test]
+    fn test_ladder_matches_double_and_add() {
+        let base = ed25519::base_point();
+        for k in [0i64, 1, 2, 7, 1000, -5] {
+            assert_eq!(
+                (base.clone() * &BigInt::from(k)).unwrap(),
+                base.mul_vartime(k).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_ed25519_base_point() {
+        // B = (x, 4/5) の位数は L = 2²⁵² + 27742317777372353535851937790883648493
+        let curve = EdwardsCurve::ed25519();
+        assert!(curve.is_complete());
+        let base = ed25519::base_point();
+        let mut encoded = vec![0x66u8; 32];
+        encoded[0] = 0x58;
+        assert_eq!(base.encode(), encoded);
+        let (x, _) = base.to_affine();
+        assert_eq!(
+            x.value(),
+            &BigUint::parse_bytes(
+                b"216936d3cd6e53fec0a4e231fdd6dc5c692cc7609525a7b2c9562d608f25d51a",
+                16
+            )
+            .unwrap()
+        );
+        assert_eq!(
+            base.to_affine().1.value() * 5u32 % &curve.prime,
+            BigUint::from(4u32)
+        );
+
+        let order = ed25519::group_order();
+        assert!(base.mul_vartime(order.clone()).unwrap().is_identity());
+        assert!(!base.mul_vartime(order - 1u32).unwrap().is_identity());
+    }
+
+    #[test]
+    fn test_decode_rejects_invalid_encodings() {
+        let curve = EdwardsCurve::ed25519();
+        // y = p は範囲外
+        let mut too_large = vec![0xFFu8; 32];
+        too_large[0] = 0xED;
+        too_large[31] = 0x7F;
+        assert!(curve.decode_point(&too_large).is_err());
+        assert!(curve.decode_point(&[0u8; 31]).is_err());
+
+        // y = 1 なら x = 0 なので、符号ビットが立っていれば不正
+        let mut identity = vec![0u8; 32];
+        identity[0] = 1;
+        assert!(curve.decode_point(&identity).unwrap().is_identity());
+        identity[31] = 0x80;
+        assert!(curve.decode_point(&identity).is_err());
+    }
+}
@@ -0,0 +1,201 @@
+use num_bigint::BigInt;
+
+use super::{Curve, CurveError};
+use crate::field::FieldElement;
+use crate::point::Point;
+
+/// j不変量・同型写像・ツイストを定義
+impl Curve {
+    /// j不変量 j = 1728·4a³ / (4a³ + 27b²) を計算
+    pub fn j_invariant(&self) -> FieldElement {
+        let four_a_cubed = self.constant(4) * &self.a * &self.a * &self.a;
+        let denominator = &four_a_cubed + self.constant(27) * &self.b * &self.b;
+        self.constant(1728) * four_a_cubed / denominator
+    }
+
+    /// F_p 上で同型かどうかを判定
+    ///
+    /// 同型写像 (x, y) ↦ (u²x, u³y) で `other` に移る場合は、
+    /// a' = u⁴a, b' = u⁶b を満たすスケール (u⁴, u⁶) を返す。
+    pub fn is_isomorphic_to(&self, other: &Curve) -> Option<(FieldElement, FieldElement)> {
+        let u = self.isomorphism_scale(other)?;
+        let u2 = &u * &u;
+        Some((&u2 * &u2, &u2 * &u2 * &u2))
+    }
+
+    /// 同型な曲線 `other` へ点を写す
+    ///
+    /// u と -u のどちらでも同型写像になるが、逆向きの写像と合成して恒等写像になるよう、
+    /// 係数 (a, b) の小さい側の曲線から見た u を使う。
+    pub fn map_point(&self, point: &Point, other: &Curve) -> Result<Point, CurveError> {
+        if point.a != self.a || point.b != self.b {
+            return Err(CurveError::InvalidParameters);
+        }
+        let u = if (self.a.value(), self.b.value()) <= (other.a.value(), other.b.value()) {
+            self.isomorphism_scale(other)
+        } else {
+            other.isomorphism_scale(self).and_then(|u| u.try_inv().ok())
+        }
+        .ok_or(CurveError::InvalidParameters)?;
+        let (x, y) = match (&point.x, &point.y) {
+            (Some(x), Some(y)) => (x, y),
+            _ => return Ok(other.infinity_point()),
+        };
+
+        let u2 = &u * &u;
+        let u3 = &u2 * &u;
+        Point::new(Some(u2 * x), Some(u3 * y), other.a.clone(), other.b.clone())
+            .map_err(|_| CurveError::PointGenerationFailed)
+    }
+
+    /// 二次ツイスト y² = x³ + d²a·x + d³b を作る
+    ///
+    /// d は平方非剰余でなければならない。#E + #E^d = 2p + 2 が成り立つ。
+    pub fn quadratic_twist(&self, non_residue: impl Into<BigInt>) -> Result<Curve, CurveError> {
+        let d = FieldElement::new(non_residue, self.prime.clone())
+            .map_err(|_| CurveError::InvalidParameters)?;
+        if d.legendre() != -1 {
+            return Err(CurveError::InvalidParameters);
+        }
+        let d2 = &d * &d;
+        let d3 = &d2 * &d;
+        Ok(Curve {
+            a: d2 * &self.a,
+            b: d3 * &self.b,
+            prime: self.prime.clone(),
+        })
+    }
+
+    /// a' = u⁴a, b' = u⁶b を満たす u を探す
+    fn isomorphism_scale(&self, other: &Curve) -> Option<FieldElement> {
+        if self.prime != other.prime || self.j_invariant() != other.j_invariant() {
+            return None;
+        }
+
+        let u = if self.a.is_zero() {
+            // j = 0: u⁶ = b'/b なので u² は b'/b の立方根
+            // 1の立方根 ω = (ω²)² は平方数なので、どの立方根を選んでも平方剰余かどうかは変わらない
+            let u2 = (&other.b / &self.b).cbrt()?;
+            u2.sqrt()?.0
+        } else if self.b.is_zero() {
+            // j = 1728: u⁴ = a'/a なので u² は a'/a の平方根のうち平方剰余のもの
+            let (v, minus_v) = (&other.a / &self.a).sqrt()?;
+            v.sqrt().or_else(|| minus_v.sqrt())?.0
+        } else {
+            // u² = u⁶/u⁴ = (b'/b)·(a/a')
+            let u2 = (&other.b / &self.b) * (&self.a / &other.a);
+            u2.sqrt()?.0
+        };
+
+        let u2 = &u * &u;
+        let u4 = &u2 * &u2;
+        let u6 = &u4 * &u2;
+        (u4 * &self.a == other.a && u6 * &self.b == other.b).then_some(u)
+    }
+
+    /// 定数を体の元として生成
+    fn constant(&self, value: i64) -> FieldElement {
+        FieldElement::new(value, self.prime.clone()).expect("prime is valid")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_bigint::BigUint;
+
+    #[test]
+    fn test_j_invariant() {
+        assert!(Curve::new(0, 7, 223).unwrap().j_invariant().is_zero());
+        assert_eq!(
+            Curve::new(1, 0, 23).unwrap().j_invariant(),
+            FieldElement::new(1728, 23).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_isomorphism_and_point_map() {
+        // u = 5 でスケールした曲線
+        let curve = Curve::new(2, 3, 97).unwrap();
+        let scaled = Curve::new(2 * 625, 3 * 15625, 97).unwrap();
+
+        let (u4, u6) = curve.is_isomorphic_to(&scaled).unwrap();
+        assert_eq!(u4, FieldElement::new(625, 97).unwrap());
+        assert_eq!(u6, FieldElement::new(15625, 97).unwrap());
+        assert_eq!(curve.j_invariant(), scaled.j_invariant());
+
+        // 点の写像は群の準同型で、逆向きに写すと元に戻る
+        let points: Vec<Point> = curve.points().take(6).collect();
+        for p in &points {
+            let mapped = curve.map_point(p, &scaled).unwrap();
+            assert_eq!(scaled.map_point(&mapped, &curve).unwrap(), *p);
+            for q in &points {
+                let sum = (p + q).unwrap();
+                let mapped_sum = (&mapped + &curve.map_point(q, &scaled).unwrap()).unwrap();
+                assert_eq!(curve.map_point(&sum, &scaled).unwrap(), mapped_sum);
+            }
+        }
+
+        // 同型な曲線の位数は等しい
+        assert_eq!(curve.order().unwrap(), scaled.order().unwrap());
+    }
+
+    #[test]
+    fn test_special_j_invariants() {
+        // j = 0: u = 3 でスケール
+        let curve = Curve::new(0, 7, 223).unwrap();
+        let scaled = Curve::new(0, 7 * 729, 223).unwrap();
+        assert!(curve.is_isomorphic_to(&scaled).is_some());
+        let point = curve.point(192, 105).unwrap();
+        let mapped = curve.map_point(&point, &scaled).unwrap();
+        assert_eq!(scaled.point_order(&mapped).unwrap(), BigUint::from(42u32));
+
+        // j = 1728: u = 2 でスケール
+        let curve = Curve::new(1, 0, 23).unwrap();
+        let scaled = Curve::new(16, 0, 23).unwrap();
+        assert!(curve.is_isomorphic_to(&scaled).is_some());
+    }
+
+    #[test]
+    fn test_quadratic_twist() {
+        let curve = Curve::new(2, 3, 97).unwrap();
+        // 5 は F97 の平方非剰余
+        let twist = curve.quadratic_twist(5).unwrap();
+        assert_eq!(twist.j_invariant(), curve.j_invariant());
+        assert!(curve.is_isomorphic_to(&twist).is_none());
+        assert!(curve
+            .map_point(&curve.points().nth(1).unwrap(), &twist)
+            .is_err());
+        assert_eq!(
+            curve.order().unwrap() + twist.order().unwrap(),
+            BigUint::from(2 * 97 + 2u32)
+        );
+
+        // 平方剰余では二次ツイストにならない
+        assert!(matches!(
+            curve.quadratic_twist(4),
+            Err(CurveError::InvalidParameters)
+        ));
+    }
+
+    #[test]
+    fn test_isomorphism_classes() {
+        // p ≡ 1 (mod 12) では F_p 上の同型類は 2p + 6 個
+        let p = 13;
+        let mut representatives: Vec<Curve> = Vec::new();
+        for a in 0..p {
+            for b in 0..p {
+                let Ok(curve) = Curve::new(a, b, p) else {
+                    continue;
+                };
+                if !representatives
+                    .iter()
+                    .any(|r| r.is_isomorphic_to(&curve).is_some())
+                {
+                    representatives.push(curve);
+                }
+            }
+        }
+        assert_eq!(representatives.len(), 2 * 13 + 6);
+    }
+}
@@ -0,0 +1,406 @@
+use num_bigint::{BigInt, BigUint};
+use num_traits::Zero;
+use std::ops::{Add, Neg};
+
+use super::{Curve, CurveError};
+use crate::field::FieldElement;
+use crate::point::{double_and_add, Point, PointError};
+
+/// 一般Weierstrass形式の曲線 y² + a₁xy + a₃y = x³ + a₂x² + a₄x + a₆
+///
+/// 標数2や3の素体 F₂, F₃ 上の曲線や、教科書の例をそのまま扱うために用いる。
+#[derive(Debug, Clone, PartialEq)]
+pub struct LongWeierstrassCurve {
+    pub a1: FieldElement,
+    pub a2: FieldElement,
+    pub a3: FieldElement,
+    pub a4: FieldElement,
+    pub a6: FieldElement,
+    pub prime: BigUint,
+}
+
+/// 一般Weierstrass形式の曲線上の点
+#[derive(Debug, Clone, PartialEq)]
+pub struct LongWeierstrassPoint {
+    pub x: Option<FieldElement>,
+    pub y: Option<FieldElement>,
+    pub curve: LongWeierstrassCurve,
+}
+
+/// 曲線の定義
+impl LongWeierstrassCurve {
+    pub fn new(
+        a1: impl Into<BigInt>,
+        a2: impl Into<BigInt>,
+        a3: impl Into<BigInt>,
+        a4: impl Into<BigInt>,
+        a6: impl Into<BigInt>,
+        prime: impl Into<BigInt>,
+    ) -> Result<Self, CurveError> {
+        let prime: BigInt = prime.into();
+        let element = |value: BigInt| {
+            FieldElement::new(value, prime.clone()).map_err(|_| CurveError::InvalidParameters)
+        };
+        let curve = Self {
+            a1: element(a1.into())?,
+            a2: element(a2.into())?,
+            a3: element(a3.into())?,
+            a4: element(a4.into())?,
+            a6: element(a6.into())?,
+            prime: prime.magnitude().clone(),
+        };
+
+        // Δ ≠ 0 の確認
+        if curve.discriminant().is_zero() {
+            return Err(CurveError::InvalidParameters);
+        }
+        Ok(curve)
+    }
+
+    /// 短縮Weierstrass形式 y² = x³ + ax + b を一般形式として表す
+    pub fn from_short(curve: &Curve) -> Self {
+        let zero = FieldElement::new(0, curve.prime.clone()).expect("prime is valid");
+        Self {
+            a1: zero.clone(),
+            a2: zero.clone(),
+            a3: zero,
+            a4: curve.a.clone(),
+            a6: curve.b.clone(),
+            prime: curve.prime.clone(),
+        }
+    }
+
+    /// b 不変量 (b₂, b₄, b₆, b₈)
+    fn b_invariants(&self) -> [FieldElement; 4] {
+        let (a1, a2, a3, a4, a6) = (&self.a1, &self.a2, &self.a3, &self.a4, &self.a6);
+        let b2 = a1 * a1 + self.constant(4) * a2;
+        let b4 = self.constant(2) * a4 + a1 * a3;
+        let b6 = a3 * a3 + self.constant(4) * a6;
+        let b8 = a1 * a1 * a6 + self.constant(4) * a2 * a6 - a1 * a3 * a4 + a2 * a3 * a3 - a4 * a4;
+        [b2, b4, b6, b8]
+    }
+
+    /// c 不変量 (c₄, c₆)
+    fn c_invariants(&self) -> [FieldElement; 2] {
+        let [b2, b4, b6, _] = self.b_invariants();
+        let c4 = &b2 * &b2 - self.constant(24) * &b4;
+        let c6 = -(&b2 * &b2 * &b2) + self.constant(36) * &b2 * &b4 - self.constant(216) * &b6;
+        [c4, c6]
+    }
+
+    /// 判別式 Δ = -b₂²b₈ - 8b₄³ - 27b₆² + 9b₂b₄b₆
+    pub fn discriminant(&self) -> FieldElement {
+        let [b2, b4, b6, b8] = self.b_invariants();
+        -(&b2 * &b2 * &b8) - self.constant(8) * &b4 * &b4 * &b4 - self.constant(27) * &b6 * &b6
+            + self.constant(9) * &b2 * &b4 * &b6
+    }
+
+    /// j不変量 c₄³ / Δ
+    pub fn j_invariant(&self) -> FieldElement {
+        let [c4, _] = self.c_invariants();
+        &c4 * &c4 * &c4 / self.discriminant()
+    }
+
+    /// 指定された座標にある点を生成
+    pub fn point(
+        &self,
+        x: impl Into<BigInt>,
+        y: impl Into<BigInt>,
+    ) -> Result<LongWeierstrassPoint, CurveError> {
+        let x = FieldElement::new(x, self.prime.clone())
+            .map_err(|_| CurveError::PointGenerationFailed)?;
+        let y = FieldElement::new(y, self.prime.clone())
+            .map_err(|_| CurveError::PointGenerationFailed)?;
+        LongWeierstrassPoint::new(Some(x), Some(y), self.clone())
+            .map_err(|_| CurveError::PointGenerationFailed)
+    }
+
+    /// 無限遠点を生成
+    pub fn infinity_point(&self) -> LongWeierstrassPoint {
+        LongWeierstrassPoint {
+            x: None,
+            y: None,
+            curve: self.clone(),
+        }
+    }
+
+    /// x 座標が与えられた点をすべて求める
+    ///
+    /// p = 2 では y ∈ {0, 1} を試し、それ以外では平方完成した
+    /// (2y + a₁x + a₃)² = 4(x³ + a₂x² + a₄x + a₆) + (a₁x + a₃)² を解く。
+    pub fn points_with_x(&self, x: impl Into<BigInt>) -> Vec<LongWeierstrassPoint> {
+        let Ok(x) = FieldElement::new(x, self.prime.clone()) else {
+            return Vec::new();
+        };
+
+        let ys = if self.prime == BigUint::from(2u32) {
+            vec![self.constant(0), self.constant(1)]
+        } else {
+            let linear = &self.a1 * &x + &self.a3;
+            let rhs = self.constant(4) * self.rhs(&x) + &linear * &linear;
+            match rhs.sqrt() {
+                Some((s, minus_s)) => {
+                    let two = self.constant(2);
+                    let mut ys = vec![(s - &linear) / &two, (minus_s - &linear) / &two];
+                    ys.sort_by(|l, r| l.value().cmp(r.value()));
+                    ys.dedup();
+                    ys
+                }
+                None => Vec::new(),
+            }
+        };
+
+        ys.into_iter()
+            .filter_map(|y| LongWeierstrassPoint::new(Some(x.clone()), Some(y), self.clone()).ok())
+            .collect()
+    }
+
+    /// 曲線上のすべての点を列挙（最初は無限遠点）
+    pub fn points(&self) -> impl Iterator<Item = LongWeierstrassPoint> + '_ {
+        let prime = self.prime.clone();
+        let xs = std::iter::successors(Some(BigUint::zero()), |x| Some(x + 1u32))
+            .take_while(move |x| *x < prime);
+        std::iter::once(self.infinity_point()).chain(xs.flat_map(move |x| self.points_with_x(x)))
+    }
+
+    /// 標数が2, 3でない場合に短縮Weierstrass形式へ変換
+    ///
+    /// x' = x + b₂/12, y' = y + (a₁x + a₃)/2 と変数変換すると
+    /// y'² = x'³ - (c₄/48)x' - c₆/864 になる。
+    pub fn to_short_weierstrass(&self) -> Result<Curve, CurveError> {
+        if self.prime <= BigUint::from(3u32) {
+            return Err(CurveError::InvalidParameters);
+        }
+        let [c4, c6] = self.c_invariants();
+        let a = -(c4 / self.constant(48));
+        let b = -(c6 / self.constant(864));
+        Curve::new(
+            BigInt::from(a.value().clone()),
+            BigInt::from(b.value().clone()),
+            BigInt::from(self.prime.clone()),
+        )
+    }
+
+    /// 点を短縮Weierstrass形式の曲線上の点に写す
+    pub fn map_to_short(&self, point: &LongWeierstrassPoint) -> Result<Point, CurveError> {
+        let short = self.to_short_weierstrass()?;
+        let (x, y) = match (&point.x, &point.y) {
+            (Some(x), Some(y)) => (x, y),
+            _ => return Ok(short.infinity_point()),
+        };
+        let [b2, ..] = self.b_invariants();
+        let x_short = x + b2 / self.constant(12);
+        let y_short = y + (&self.a1 * x + &self.a3) / self.constant(2);
+        Point::new(Some(x_short), Some(y_short), short.a, short.b)
+            .map_err(|_| CurveError::PointGenerationFailed)
+    }
+
+    /// 短縮Weierstrass形式の曲線上の点を一般形式の点に戻す
+    pub fn map_from_short(&self, point: &Point) -> Result<LongWeierstrassPoint, CurveError> {
+        let short = self.to_short_weierstrass()?;
+        if point.a != short.a || point.b != short.b {
+            return Err(CurveError::InvalidParameters);
+        }
+        let (x_short, y_short) = match (&point.x, &point.y) {
+            (Some(x), Some(y)) => (x, y),
+            _ => return Ok(self.infinity_point()),
+        };
+        let [b2, ..] = self.b_invariants();
+        let x = x_short - b2 / self.constant(12);
+        let y = y_short - (&self.a1 * &x + &self.a3) / self.constant(2);
+        LongWeierstrassPoint::new(Some(x), Some(y), self.clone())
+            .map_err(|_| CurveError::PointGenerationFailed)
+    }
+
+    /// x³ + a₂x² + a₄x + a₆ を計算
+    fn rhs(&self, x: &FieldElement) -> FieldElement {
+        ((x + &self.a2) * x + &self.a4) * x + &self.a6
+    }
+
+    /// 定数を体の元として生成
+    fn constant(&self, value: i64) -> FieldElement {
+        FieldElement::new(value, self.prime.clone()).expect("prime is valid")
+    }
+}
+
+/// 点の生成と操作を定義
+impl LongWeierstrassPoint {
+    pub fn new(
+        x: Option<FieldElement>,
+        y: Option<FieldElement>,
+        curve: LongWeierstrassCurve,
+    ) -> Result<Self, PointError> {
+        match (&x, &y) {
+            (None, None) => Ok(Self { x, y, curve }),
+            (Some(x_val), Some(y_val)) => {
+                // y² + a₁xy + a₃y = x³ + a₂x² + a₄x + a₆ の確認
+                let lhs = y_val
+                    .checked_mul(y_val)?
+                    .checked_add(&curve.a1.checked_mul(x_val)?.checked_mul(y_val)?)?
+                    .checked_add(&curve.a3.checked_mul(y_val)?)?;
+                if x_val.prime() != &curve.prime || lhs != curve.rhs(x_val) {
+                    return Err(PointError::NotOnCurve);
+                }
+                Ok(Self { x, y, curve })
+            }
+            _ => Err(PointError::NotOnCurve),
+        }
+    }
+
+    pub fn is_infinity(&self) -> bool {
+        self.x.is_none() && self.y.is_none()
+    }
+
+    /// 公開スカラーによる可変時間のスカラー倍算（double-and-add）
+    pub fn mul_vartime(&self, scalar: impl Into<BigInt>) -> Result<Self, PointError> {
+        double_and_add(self, self.curve.infinity_point(), &scalar.into())
+    }
+}
+
+/// 一般Weierstrass形式の加算を定義
+impl Add<&LongWeierstrassPoint> for &LongWeierstrassPoint {
+    type Output = Result<LongWeierstrassPoint, PointError>;
+
+    fn add(self, other: &LongWeierstrassPoint) -> Result<LongWeierstrassPoint, PointError> {
+        if self.curve != other.curve {
+            return Err(PointError::DifferentCurves);
+        }
+
+        let (x1, y1, x2, y2) = match (&self.x, &self.y, &other.x, &other.y) {
+            (None, None, _, _) => return Ok(other.clone()),
+            (_, _, None, None) => return Ok(self.clone()),
+            (Some(x1), Some(y1), Some(x2), Some(y2)) => (x1, y1, x2, y2),
+            _ => return Err(PointError::NotOnCurve),
+        };
+        let curve = &self.curve;
+
+        // Q = -P の場合: -P = (x, -y - a₁x - a₃)
+        if x1 == x2 && (y1 + y2 + &curve.a1 * x2 + &curve.a3).is_zero() {
+            return Ok(curve.infinity_point());
+        }
+
+        // 接線または割線の傾き λ
+        let slope = if x1 == x2 {
+            let numerator =
+                curve.constant(3) * x1 * x1 + curve.constant(2) * &curve.a2 * x1 + &curve.a4
+                    - &curve.a1 * y1;
+            let denominator = curve.constant(2) * y1 + &curve.a1 * x1 + &curve.a3;
+            numerator.checked_div(&denominator)?
+        } else {
+            (y2 - y1).checked_div(&(x2 - x1))?
+        };
+        let intercept = y1 - &slope * x1;
+
+        // x₃ = λ² + a₁λ - a₂ - x₁ - x₂, y₃ = -(λ + a₁)x₃ - ν - a₃
+        let x3 = &slope * &slope + &curve.a1 * &slope - &curve.a2 - x1 - x2;
+        let y3 = -((&slope + &curve.a1) * &x3) - intercept - &curve.a3;
+        Ok(LongWeierstrassPoint {
+            x: Some(x3),
+            y: Some(y3),
+            curve: curve.clone(),
+        })
+    }
+}
+
+/// 一般Weierstrass形式の逆元を定義
+impl Neg for LongWeierstrassPoint {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        let y = match (&self.x, &self.y) {
+            (Some(x), Some(y)) => Some(-y - &self.curve.a1 * x - &self.curve.a3),
+            _ => None,
+        };
+        Self { y, ..self }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 群の公理をすべての点の組で確かめる
+    fn check_group_law(curve: &LongWeierstrassCurve, expected_order: usize) {
+        let points: Vec<LongWeierstrassPoint> = curve.points().collect();
+        assert_eq!(points.len(), expected_order);
+
+        for p in &points {
+            assert_eq!((p + &-p.clone()).unwrap(), curve.infinity_point());
+            assert!(p.mul_vartime(expected_order as i64).unwrap().is_infinity());
+            for q in &points {
+                let pq = (p + q).unwrap();
+                assert_eq!(pq, (q + p).unwrap());
+                for r in &points {
+                    assert_eq!((&pq + r).unwrap(), (p + &(q + r).unwrap()).unwrap());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_characteristic_two() {
+        // y² + xy = x³ + 1 over F₂
+        let curve = LongWeierstrassCurve::new(1, 0, 0, 0, 1, 2).unwrap();
+        check_group_law(&curve, 4);
+        assert!(curve.to_short_weierstrass().is_err());
+    }
+
+    #[test]
+    fn test_characteristic_three() {
+        // y² = x³ + x² + 1 over F₃
+        let curve = LongWeierstrassCurve::new(0, 1, 0, 0, 1, 3).unwrap();
+        check_group_law(&curve, 6);
+    }
+
+    #[test]
+    fn test_general_coefficients() {
+        let curve = LongWeierstrassCurve::new(1, 1, 1, 1, 1, 5).unwrap();
+        check_group_law(&curve, 8);
+    }
+
+    #[test]
+    fn test_singular_curve_rejected() {
+        // y² = x³ は特異
+        assert!(matches!(
+            LongWeierstrassCurve::new(0, 0, 0, 0, 0, 7),
+            Err(CurveError::InvalidParameters)
+        ));
+        // y² + xy = x³ over F₂ も特異
+        assert!(LongWeierstrassCurve::new(1, 0, 0, 0, 0, 2).is_err());
+    }
+
+    #[test]
+    fn test_conversion_to_short_form() {
+        let curve = LongWeierstrassCurve::new(1, 2, 3, 4, 5, 97).unwrap();
+        let short = curve.to_short_weierstrass().unwrap();
+        assert_eq!(curve.j_invariant(), short.j_invariant());
+        assert_eq!(
+            BigUint::from(curve.points().count()),
+            short.order().unwrap()
+        );
+
+        // 変数変換は群の同型写像
+        let points: Vec<LongWeierstrassPoint> = curve.points().take(12).collect();
+        for p in &points {
+            let mapped = curve.map_to_short(p).unwrap();
+            assert_eq!(curve.map_from_short(&mapped).unwrap(), *p);
+            for q in &points {
+                let sum = curve.map_to_short(&(p + q).unwrap()).unwrap();
+                assert_eq!(sum, (&mapped + &curve.map_to_short(q).unwrap()).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_short() {
+        let short = Curve::new(0, 7, 223).unwrap();
+        let long = LongWeierstrassCurve::from_short(&short);
+        let point = long.point(192, 105).unwrap();
+        assert_eq!(
+            long.map_to_short(&point).unwrap(),
+            short.point(192, 105).unwrap()
+        );
+        assert!(point.mul_vartime(42).unwrap().is_infinity());
+        assert!(!point.mul_vartime(21).unwrap().is_infinity());
+    }
+}
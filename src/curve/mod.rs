@@ -0,0 +1,333 @@
+use crate::field::FieldElement;
+use crate::point::Point;
+use num_bigint::{BigInt, BigUint};
+use thiserror::Error;
+
+pub mod edwards;
+mod isomorphism;
+pub mod long_weierstrass;
+pub mod montgomery;
+pub mod named;
+mod order;
+mod points;
+mod schoof;
+mod security;
+mod structure;
+mod subgroup;
+
+pub use edwards::{EdwardsCurve, EdwardsPoint};
+pub use long_weierstrass::{LongWeierstrassCurve, LongWeierstrassPoint};
+pub use montgomery::{MontgomeryCurve, MontgomeryPoint};
+pub use named::CurveParams;
+pub use security::{SecurityReport, Weakness};
+pub use structure::GroupStructure;
+
+#[derive(Error, Debug)]
+pub enum CurveError {
+    #[error("Invalid curve parameters")]
+    InvalidParameters,
+    #[error("Point generation failed")]
+    PointGenerationFailed,
+    #[error("No point on the curve has the given x-coordinate")]
+    InvalidXCoordinate,
+    #[error("Invalid point encoding")]
+    InvalidEncoding,
+}
+
+#[derive(Debug, Clone)]
+pub struct Curve {
+    pub a: FieldElement,
+    pub b: FieldElement,
+    pub prime: BigUint,
+}
+
+/// 曲線の定義
+impl Curve {
+    pub fn new(
+        a: impl Into<BigInt>,
+        b: impl Into<BigInt>,
+        prime: impl Into<BigInt>,
+    ) -> Result<Self, CurveError> {
+        let prime: BigInt = prime.into();
+        let a = FieldElement::new(a, prime.clone()).map_err(|_| CurveError::InvalidParameters)?;
+        let b = FieldElement::new(b, prime.clone()).map_err(|_| CurveError::InvalidParameters)?;
+
+        // 4a³ + 27b² ≠ 0 の確認
+        let a_cubed = &a * &a * &a * FieldElement::new(4, prime.clone()).unwrap();
+        let b_squared = &b * &b * FieldElement::new(27, prime.clone()).unwrap();
+        if (a_cubed + b_squared).is_zero() {
+            return Err(CurveError::InvalidParameters);
+        }
+
+        Ok(Self {
+            a,
+            b,
+            prime: prime.magnitude().clone(),
+        })
+    }
+
+    /// 指定された座標にある点を生成
+    pub fn point(&self, x: impl Into<BigInt>, y: impl Into<BigInt>) -> Result<Point, CurveError> {
+        let x = FieldElement::new(x, self.prime.clone())
+            .map_err(|_| CurveError::PointGenerationFailed)?;
+        let y = FieldElement::new(y, self.prime.clone())
+            .map_err(|_| CurveError::PointGenerationFailed)?;
+        Point::new(Some(x), Some(y), self.a.clone(), self.b.clone())
+            .map_err(|_| CurveError::PointGenerationFailed)
+    }
+
+    /// x座標とyの偶奇から点を生成
+    ///
+    /// y² = x³ + ax + b を解き、y の偶奇が `y_is_odd` に一致する方の点を返す。
+    pub fn lift_x(&self, x: impl Into<BigInt>, y_is_odd: bool) -> Result<Point, CurveError> {
+        let x = FieldElement::new(x, self.prime.clone())
+            .map_err(|_| CurveError::PointGenerationFailed)?;
+        let (y1, y2) = self.rhs(&x).sqrt().ok_or(CurveError::InvalidXCoordinate)?;
+        let y = if y1.value().bit(0) == y_is_odd {
+            y1
+        } else if y2.value().bit(0) == y_is_odd {
+            y2
+        } else {
+            // y = 0 のときは偶数の根しか存在しない
+            return Err(CurveError::InvalidXCoordinate);
+        };
+        Point::new(Some(x), Some(y), self.a.clone(), self.b.clone())
+            .map_err(|_| CurveError::PointGenerationFailed)
+    }
+
+    /// 指定されたx座標を持つすべての点を生成
+    ///
+    /// y の値が小さい順に、y ≠ 0 なら2点、y = 0 なら1点を返す。
+    pub fn points_with_x(&self, x: impl Into<BigInt>) -> Result<Vec<Point>, CurveError> {
+        let x = FieldElement::new(x, self.prime.clone())
+            .map_err(|_| CurveError::PointGenerationFailed)?;
+        let (y1, y2) = self.rhs(&x).sqrt().ok_or(CurveError::InvalidXCoordinate)?;
+
+        let mut ys = vec![y1, y2];
+        ys.sort_by(|l, r| l.value().cmp(r.value()));
+        ys.dedup();
+        ys.into_iter()
+            .map(|y| {
+                Point::new(Some(x.clone()), Some(y), self.a.clone(), self.b.clone())
+                    .map_err(|_| CurveError::PointGenerationFailed)
+            })
+            .collect()
+    }
+
+    /// SEC1形式のバイト列から点を復元
+    ///
+    /// `0x00`（無限遠点）、`0x02`/`0x03`（圧縮形式）、`0x04`（非圧縮形式）に対応する。
+    /// 曲線上にない点は拒否する。
+    pub fn point_from_sec1(&self, bytes: &[u8]) -> Result<Point, CurveError> {
+        let len = self.prime.bits().div_ceil(8) as usize;
+        let (&prefix, body) = bytes.split_first().ok_or(CurveError::InvalidEncoding)?;
+
+        // 座標は [0, p) の範囲でなければならない
+        let coordinate = |bytes: &[u8]| {
+            let value = BigUint::from_bytes_be(bytes);
+            if value < self.prime {
+                Ok(value)
+            } else {
+                Err(CurveError::InvalidEncoding)
+            }
+        };
+
+        match prefix {
+            0x00 if body.is_empty() => Ok(self.infinity_point()),
+            0x02 | 0x03 if body.len() == len => self.lift_x(coordinate(body)?, prefix == 0x03),
+            0x04 if body.len() == 2 * len => {
+                let (x, y) = body.split_at(len);
+                self.point(coordinate(x)?, coordinate(y)?)
+            }
+            _ => Err(CurveError::InvalidEncoding),
+        }
+    }
+
+    /// 曲線の右辺 x³ + ax + b を計算
+    fn rhs(&self, x: &FieldElement) -> FieldElement {
+        x * x * x + &self.a * x + &self.b
+    }
+
+    /// 無限遠点を生成
+    pub fn infinity_point(&self) -> Point {
+        Point::new(None, None, self.a.clone(), self.b.clone()).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_curve_creation() {
+        // y² = x³ + 7 over F223
+        let curve = Curve::new(0, 7, 223).unwrap();
+        assert_eq!(curve.a.value(), &BigUint::from(0u32));
+        assert_eq!(curve.b.value(), &BigUint::from(7u32));
+        assert_eq!(curve.prime, BigUint::from(223u32));
+    }
+
+    #[test]
+    fn test_point_creation_on_curve() {
+        let curve = Curve::new(0, 7, 223).unwrap();
+        let point = curve.point(192, 105).unwrap();
+        assert_eq!(point.x.unwrap().value(), &BigUint::from(192u32));
+        assert_eq!(point.y.unwrap().value(), &BigUint::from(105u32));
+    }
+
+    #[test]
+    fn test_invalid_point() {
+        let curve = Curve::new(0, 7, 223).unwrap();
+        assert!(curve.point(200, 119).is_err()); // This point is not on the curve
+    }
+
+    #[test]
+    fn test_infinity_point() {
+        let curve = Curve::new(0, 7, 223).unwrap();
+        let point = curve.infinity_point();
+        assert!(point.is_infinity());
+    }
+
+    #[test]
+    fn test_point_order() {
+        let curve = Curve::new(0, 7, 223).unwrap();
+        let point = curve.point(192, 105).unwrap();
+
+        // 計算した位数が正しいことを確認
+        let order = curve.point_order(&point).unwrap();
+        let mul_result = (point * &order).unwrap();
+        assert!(mul_result.is_infinity());
+    }
+
+    #[test]
+    fn test_large_prime_curve() {
+        // secp256k1: y² = x³ + 7 over F_p, p = 2²⁵⁶ - 2³² - 977
+        let prime: BigInt = (BigInt::from(1) << 256) - (BigInt::from(1) << 32) - 977;
+        let curve = Curve::new(0, 7, prime).unwrap();
+        let gx = BigInt::parse_bytes(
+            b"79BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798",
+            16,
+        )
+        .unwrap();
+        let gy = BigInt::parse_bytes(
+            b"483ADA7726A3C4655DA4FBFC0E1108A8FD17B448A68554199C47D08FFB10D4B8",
+            16,
+        )
+        .unwrap();
+        let g = curve.point(gx, gy).unwrap();
+
+        // 2G = G + G
+        let doubled = (&g + &g).unwrap();
+        let expected_x = BigUint::parse_bytes(
+            b"C6047F9441ED7D6D3045406E95C07CD85C778E4B8CEF3CA7ABAC09B95C709EE5",
+            16,
+        )
+        .unwrap();
+        assert_eq!(doubled.x.as_ref().unwrap().value(), &expected_x);
+        assert_eq!((g * 2).unwrap(), doubled);
+    }
+
+    #[test]
+    fn test_lift_x() {
+        let curve = Curve::new(0, 7, 223).unwrap();
+
+        // (192, 105) と (192, 118) が曲線上にある
+        let odd = curve.lift_x(192, true).unwrap();
+        let even = curve.lift_x(192, false).unwrap();
+        assert_eq!(odd, curve.point(192, 105).unwrap());
+        assert_eq!(even, curve.point(192, 118).unwrap());
+        assert_eq!(odd, -even);
+    }
+
+    #[test]
+    fn test_lift_x_not_on_curve() {
+        let curve = Curve::new(0, 7, 223).unwrap();
+        // 191³ + 7 は F223 の平方非剰余
+        assert!(matches!(
+            curve.lift_x(191, false),
+            Err(CurveError::InvalidXCoordinate)
+        ));
+        assert!(matches!(
+            curve.points_with_x(191),
+            Err(CurveError::InvalidXCoordinate)
+        ));
+    }
+
+    #[test]
+    fn test_points_with_x() {
+        let curve = Curve::new(0, 7, 223).unwrap();
+        let points = curve.points_with_x(17).unwrap();
+        assert_eq!(points.len(), 2);
+        assert_eq!(points[0], curve.point(17, 56).unwrap());
+        assert_eq!(points[1], curve.point(17, 167).unwrap());
+    }
+
+    #[test]
+    fn test_points_with_x_two_torsion() {
+        // y² = x³ - x over F23 は (0, 0) を持つ
+        let curve = Curve::new(-1, 0, 23).unwrap();
+        let points = curve.points_with_x(0).unwrap();
+        assert_eq!(points, vec![curve.point(0, 0).unwrap()]);
+        assert!(curve.lift_x(0, false).is_ok());
+        assert!(matches!(
+            curve.lift_x(0, true),
+            Err(CurveError::InvalidXCoordinate)
+        ));
+    }
+
+    #[test]
+    fn test_sec1_roundtrip() {
+        let curve = Curve::new(0, 7, 223).unwrap();
+        let point = curve.point(192, 105).unwrap();
+
+        let compressed = point.to_sec1(true);
+        assert_eq!(compressed, vec![0x03, 192]);
+        let uncompressed = point.to_sec1(false);
+        assert_eq!(uncompressed, vec![0x04, 192, 105]);
+
+        assert_eq!(curve.point_from_sec1(&compressed).unwrap(), point);
+        assert_eq!(curve.point_from_sec1(&uncompressed).unwrap(), point);
+
+        let infinity = curve.infinity_point();
+        assert_eq!(infinity.to_sec1(true), vec![0x00]);
+        assert!(curve.point_from_sec1(&[0x00]).unwrap().is_infinity());
+    }
+
+    #[test]
+    fn test_sec1_secp256k1_generator() {
+        let prime: BigInt = (BigInt::from(1) << 256) - (BigInt::from(1) << 32) - 977;
+        let curve = Curve::new(0, 7, prime).unwrap();
+        let compressed = [
+            0x02, 0x79, 0xBE, 0x66, 0x7E, 0xF9, 0xDC, 0xBB, 0xAC, 0x55, 0xA0, 0x62, 0x95, 0xCE,
+            0x87, 0x0B, 0x07, 0x02, 0x9B, 0xFC, 0xDB, 0x2D, 0xCE, 0x28, 0xD9, 0x59, 0xF2, 0x81,
+            0x5B, 0x16, 0xF8, 0x17, 0x98,
+        ];
+        let generator = curve.point_from_sec1(&compressed).unwrap();
+        assert_eq!(generator.to_sec1(true), compressed.to_vec());
+
+        let uncompressed = generator.to_sec1(false);
+        assert_eq!(uncompressed.len(), 65);
+        assert_eq!(curve.point_from_sec1(&uncompressed).unwrap(), generator);
+    }
+
+    #[test]
+    fn test_sec1_rejects_invalid_encodings() {
+        let curve = Curve::new(0, 7, 223).unwrap();
+        // 曲線上にない点
+        assert!(curve.point_from_sec1(&[0x04, 200, 119]).is_err());
+        // x³ + 7 が平方非剰余になるx座標
+        assert!(curve.point_from_sec1(&[0x02, 191]).is_err());
+        // p 以上の座標
+        assert!(matches!(
+            curve.point_from_sec1(&[0x02, 230]),
+            Err(CurveError::InvalidEncoding)
+        ));
+        // 長さや接頭辞が不正
+        for bytes in [&[][..], &[0x00, 0x00], &[0x04, 192], &[0x05, 192, 105]] {
+            assert!(matches!(
+                curve.point_from_sec1(bytes),
+                Err(CurveError::InvalidEncoding)
+            ));
+        }
+    }
+}
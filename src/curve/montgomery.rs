@@ -0,0 +1,388 @@
+use num_bigint::{BigInt, BigUint};
+use std::ops::{Add, Neg};
+
+use super::{Curve, CurveError};
+use crate::field::FieldElement;
+use crate::point::{conditional_swap, double_and_add, Point, PointError};
+
+/// Montgomery曲線 Bv² = u³ + Au² + u
+///
+/// u 座標だけでスカラー倍算 (Montgomeryラダー) ができるので、X25519 / X448 に用いられる。
+#[derive(Debug, Clone, PartialEq)]
+pub struct MontgomeryCurve {
+    pub a: FieldElement,
+    pub b: FieldElement,
+    pub prime: BigUint,
+}
+
+/// Montgomery曲線上の点
+#[derive(Debug, Clone, PartialEq)]
+pub struct MontgomeryPoint {
+    pub u: Option<FieldElement>,
+    pub v: Option<FieldElement>,
+    pub curve: MontgomeryCurve,
+}
+
+/// 曲線の定義
+impl MontgomeryCurve {
+    pub fn new(
+        a: impl Into<BigInt>,
+        b: impl Into<BigInt>,
+        prime: impl Into<BigInt>,
+    ) -> Result<Self, CurveError> {
+        let prime: BigInt = prime.into();
+        let a = FieldElement::new(a, prime.clone()).map_err(|_| CurveError::InvalidParameters)?;
+        let b = FieldElement::new(b, prime.clone()).map_err(|_| CurveError::InvalidParameters)?;
+
+        // 標数2では定義しない。B(A² - 4) ≠ 0 でなければ特異になる
+        let four =
+            FieldElement::new(4, prime.clone()).map_err(|_| CurveError::InvalidParameters)?;
+        if !prime.bit(0) || b.is_zero() || &a * &a == four {
+            return Err(CurveError::InvalidParameters);
+        }
+        Ok(Self {
+            a,
+            b,
+            prime: prime.magnitude().clone(),
+        })
+    }
+
+    /// Curve25519 v² = u³ + 486662u² + u over F(2²⁵⁵ - 19)
+    pub fn curve25519() -> Self {
+        let prime = (BigInt::from(1) << 255u32) - 19;
+        Self::new(486662, 1, prime).expect("Curve25519 parameters are valid")
+    }
+
+    /// Curve448 v² = u³ + 156326u² + u over F(2⁴⁴⁸ - 2²²⁴ - 1)
+    pub fn curve448() -> Self {
+        let prime = (BigInt::from(1) << 448u32) - (BigInt::from(1) << 224u32) - 1;
+        Self::new(156326, 1, prime).expect("Curve448 parameters are valid")
+    }
+
+    /// 指定された座標にある点を生成
+    pub fn point(
+        &self,
+        u: impl Into<BigInt>,
+        v: impl Into<BigInt>,
+    ) -> Result<MontgomeryPoint, CurveError> {
+        let u = FieldElement::new(u, self.prime.clone())
+            .map_err(|_| CurveError::PointGenerationFailed)?;
+        let v = FieldElement::new(v, self.prime.clone())
+            .map_err(|_| CurveError::PointGenerationFailed)?;
+        MontgomeryPoint::new(Some(u), Some(v), self.clone())
+            .map_err(|_| CurveError::PointGenerationFailed)
+    }
+
+    /// 無限遠点を生成
+    pub fn infinity_point(&self) -> MontgomeryPoint {
+        MontgomeryPoint {
+            u: None,
+            v: None,
+            curve: self.clone(),
+        }
+    }
+
+    /// u 座標が与えられた点をすべて求める
+    pub fn points_with_u(&self, u: impl Into<BigInt>) -> Vec<MontgomeryPoint> {
+        let Ok(u) = FieldElement::new(u, self.prime.clone()) else {
+            return Vec::new();
+        };
+        let Ok(v2) = self.rhs(&u).checked_div(&self.b) else {
+            return Vec::new();
+        };
+        match v2.sqrt() {
+            Some((v, minus_v)) => {
+                let mut vs = vec![v, minus_v];
+                vs.sort_by(|l, r| l.value().cmp(r.value()));
+                vs.dedup();
+                vs.into_iter()
+                    .map(|v| MontgomeryPoint {
+                        u: Some(u.clone()),
+                        v: Some(v),
+                        curve: self.clone(),
+                    })
+                    .collect()
+            }
+            None => Vec::new(),
+        }
+    }
+
+    /// u 座標だけを用いたMontgomeryラダーで k·P の u 座標を計算 (RFC 7748 5節)
+    ///
+    /// 射影座標 (X : Z) で xDBL と xADD を繰り返し、最後に X·Z^(p-2) を返す。
+    /// 結果が無限遠点の場合は Z = 0 なので 0 を返す。v 座標や B には依存しない。
+    /// ビット長は p のビット長に揃え、スカラーに関わらず同じ演算列を実行する。
+    pub fn ladder(&self, u: &FieldElement, scalar: &BigUint) -> FieldElement {
+        let a24 = (&self.a - self.constant(2)) / self.constant(4);
+        let bits = scalar.bits().max(self.prime.bits());
+
+        let x1 = u.clone();
+        let (mut x2, mut z2) = (self.constant(1), self.constant(0));
+        let (mut x3, mut z3) = (u.clone(), self.constant(1));
+        let mut swap = false;
+
+        for t in (0..bits).rev() {
+            let bit = scalar.bit(t);
+            swap ^= bit;
+            conditional_swap(&mut x2, &mut x3, swap);
+            conditional_swap(&mut z2, &mut z3, swap);
+            swap = bit;
+
+            let a = &x2 + &z2;
+            let aa = &a * &a;
+            let b = &x2 - &z2;
+            let bb = &b * &b;
+            let e = &aa - &bb;
+            let c = &x3 + &z3;
+            let d = &x3 - &z3;
+            let da = d * &a;
+            let cb = c * &b;
+
+            let sum = &da + &cb;
+            let difference = &da - &cb;
+            x3 = &sum * &sum;
+            z3 = &x1 * &difference * &difference;
+            z2 = &e * (&aa + &a24 * &e);
+            x2 = aa * bb;
+        }
+        conditional_swap(&mut x2, &mut x3, swap);
+        conditional_swap(&mut z2, &mut z3, swap);
+
+        // Z = 0 のときは Z^(p-2) = 0 になる
+        let exponent = BigInt::from(&self.prime - 2u32);
+        x2 * z2.pow(exponent).expect("exponent is non-negative")
+    }
+
+    /// 対応する短縮Weierstrass形式の曲線を求める
+    ///
+    /// u = Bx - A/3, v = By と変数変換すると
+    /// y² = x³ + ((3 - A²)/(3B²))x + (2A³ - 9A)/(27B³) になる。標数3では定義しない。
+    pub fn to_weierstrass(&self) -> Result<Curve, CurveError> {
+        if self.prime == BigUint::from(3u32) {
+            return Err(CurveError::InvalidParameters);
+        }
+        let (a, b) = (&self.a, &self.b);
+        let weierstrass_a = (self.constant(3) - a * a) / (self.constant(3) * b * b);
+        let weierstrass_b =
+            (self.constant(2) * a * a * a - self.constant(9) * a) / (self.constant(27) * b * b * b);
+        Curve::new(
+            BigInt::from(weierstrass_a.value().clone()),
+            BigInt::from(weierstrass_b.value().clone()),
+            BigInt::from(self.prime.clone()),
+        )
+    }
+
+    /// 点を短縮Weierstrass形式の曲線上の点 (u/B + A/(3B), v/B) に写す
+    pub fn map_to_weierstrass(&self, point: &MontgomeryPoint) -> Result<Point, CurveError> {
+        let weierstrass = self.to_weierstrass()?;
+        let (u, v) = match (&point.u, &point.v) {
+            (Some(u), Some(v)) => (u, v),
+            _ => return Ok(weierstrass.infinity_point()),
+        };
+        let x = (u + &self.a / self.constant(3)) / &self.b;
+        let y = v / &self.b;
+        Point::new(Some(x), Some(y), weierstrass.a, weierstrass.b)
+            .map_err(|_| CurveError::PointGenerationFailed)
+    }
+
+    /// 短縮Weierstrass形式の曲線上の点を Montgomery曲線の点 (Bx - A/3, By) に戻す
+    pub fn map_from_weierstrass(&self, point: &Point) -> Result<MontgomeryPoint, CurveError> {
+        let weierstrass = self.to_weierstrass()?;
+        if point.a != weierstrass.a || point.b != weierstrass.b {
+            return Err(CurveError::InvalidParameters);
+        }
+        let (x, y) = match (&point.x, &point.y) {
+            (Some(x), Some(y)) => (x, y),
+            _ => return Ok(self.infinity_point()),
+        };
+        let u = &self.b * x - &self.a / self.constant(3);
+        let v = &self.b * y;
+        MontgomeryPoint::new(Some(u), Some(v), self.clone())
+            .map_err(|_| CurveError::PointGenerationFailed)
+    }
+
+    /// u³ + Au² + u を計算
+    fn rhs(&self, u: &FieldElement) -> FieldElement {
+        u * u * u + &self.a * u * u + u
+    }
+
+    /// 定数を体の元として生成
+    fn constant(&self, value: i64) -> FieldElement {
+        FieldElement::new(value, self.prime.clone()).expect("prime is valid")
+    }
+}
+
+/// 点の生成と操作を定義
+impl MontgomeryPoint {
+    pub fn new(
+        u: Option<FieldElement>,
+        v: Option<FieldElement>,
+        curve: MontgomeryCurve,
+    ) -> Result<Self, PointError> {
+        match (&u, &v) {
+            (None, None) => Ok(Self { u, v, curve }),
+            (Some(u_val), Some(v_val)) => {
+                // Bv² = u³ + Au² + u の確認
+                if u_val.prime() != &curve.prime
+                    || v_val.prime() != &curve.prime
+                    || &curve.b * v_val * v_val != curve.rhs(u_val)
+                {
+                    return Err(PointError::NotOnCurve);
+                }
+                Ok(Self { u, v, curve })
+            }
+            _ => Err(PointError::NotOnCurve),
+        }
+    }
+
+    pub fn is_infinity(&self) -> bool {
+        self.u.is_none() && self.v.is_none()
+    }
+
+    /// 公開スカラーによる可変時間のスカラー倍算（double-and-add）
+    pub fn mul_vartime(&self, scalar: impl Into<BigInt>) -> Result<Self, PointError> {
+        double_and_add(self, self.curve.infinity_point(), &scalar.into())
+    }
+}
+
+/// Montgomery形式の加算を定義
+impl Add<&MontgomeryPoint> for &MontgomeryPoint {
+    type Output = Result<MontgomeryPoint, PointError>;
+
+    fn add(self, other: &MontgomeryPoint) -> Result<MontgomeryPoint, PointError> {
+        if self.curve != other.curve {
+            return Err(PointError::DifferentCurves);
+        }
+
+        let (u1, v1, u2, v2) = match (&self.u, &self.v, &other.u, &other.v) {
+            (None, None, _, _) => return Ok(other.clone()),
+            (_, _, None, None) => return Ok(self.clone()),
+            (Some(u1), Some(v1), Some(u2), Some(v2)) => (u1, v1, u2, v2),
+            _ => return Err(PointError::NotOnCurve),
+        };
+        let curve = &self.curve;
+
+        // P + (-P) = O
+        if u1 == u2 && (v1 + v2).is_zero() {
+            return Ok(curve.infinity_point());
+        }
+
+        // λ = (3u₁² + 2Au₁ + 1) / (2Bv₁) または (v₂ - v₁) / (u₂ - u₁)
+        let slope = if u1 == u2 {
+            let numerator =
+                curve.constant(3) * u1 * u1 + curve.constant(2) * &curve.a * u1 + curve.constant(1);
+            numerator.checked_div(&(curve.constant(2) * &curve.b * v1))?
+        } else {
+            (v2 - v1).checked_div(&(u2 - u1))?
+        };
+
+        // u₃ = Bλ² - A - u₁ - u₂, v₃ = λ(u₁ - u₃) - v₁
+        let u3 = &curve.b * &slope * &slope - &curve.a - u1 - u2;
+        let v3 = &slope * (u1 - &u3) - v1;
+        Ok(MontgomeryPoint {
+            u: Some(u3),
+            v: Some(v3),
+            curve: curve.clone(),
+        })
+    }
+}
+
+/// Montgomery形式の逆元 -(u, v) = (u, -v) を定義
+impl Neg for MontgomeryPoint {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            v: self.v.map(|v| -v),
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 曲線上のすべての点（最初は無限遠点）
+    fn all_points(curve: &MontgomeryCurve) -> Vec<MontgomeryPoint> {
+        let p = curve.prime.to_u64_digits()[0];
+        std::iter::once(curve.infinity_point())
+            .chain((0..p).flat_map(|u| curve.points_with_u(u)))
+            .collect()
+    }
+
+    #[test]
+    fn test_birational_map_is_group_isomorphism() {
+        // 3v² = u³ + 5u² + u over F₁₀₁
+        let curve = MontgomeryCurve::new(5, 3, 101).unwrap();
+        let weierstrass = curve.to_weierstrass().unwrap();
+        let points = all_points(&curve);
+        assert_eq!(BigUint::from(points.len()), weierstrass.order().unwrap());
+
+        for p in &points {
+            let mapped = curve.map_to_weierstrass(p).unwrap();
+            assert_eq!(curve.map_from_weierstrass(&mapped).unwrap(), *p);
+            for q in points.iter().step_by(7) {
+                let sum = curve.map_to_weierstrass(&(p + q).unwrap()).unwrap();
+                assert_eq!(
+                    sum,
+                    (&mapped + &curve.map_to_weierstrass(q).unwrap()).unwrap()
+                );
+            }
+        }
+
+        let other = Curve::new(0, 7, 101).unwrap();
+        assert!(curve.map_from_weierstrass(&other.infinity_point()).is_err());
+    }
+
+    #[test]
+    fn test_ladder_matches_group_law() {
+        let curve = MontgomeryCurve::new(5, 3, 101).unwrap();
+        for point in all_points(&curve).iter().skip(1) {
+            let u = point.u.clone().unwrap();
+            for k in 0u32..20 {
+                let expected = point.mul_vartime(k).unwrap();
+                let expected_u = expected.u.unwrap_or_else(|| curve.constant(0));
+                assert_eq!(curve.ladder(&u, &BigUint::from(k)), expected_u);
+            }
+        }
+    }
+
+    #[test]
+    fn test_curve25519_base_point() {
+        // 基点 u = 9 の位数は Ed25519 の基点と同じ L = 2²⁵² + 27742317777372353535851937790883648493
+        let curve = MontgomeryCurve::curve25519();
+        let base = curve.points_with_u(9).pop().unwrap();
+        let order = crate::protocols::ed25519::group_order();
+
+        let mapped = curve.map_to_weierstrass(&base).unwrap();
+        assert!(mapped.is_in_prime_order_subgroup(&order));
+
+        // Weierstrass形式でのスカラー倍算とラダーの結果が一致する
+        let scalar = BigUint::from(0x1234_5678_9abc_def0u64);
+        let product = curve
+            .map_from_weierstrass(&mapped.mul_vartime(scalar.clone()).unwrap())
+            .unwrap();
+        assert_eq!(
+            curve.ladder(base.u.as_ref().unwrap(), &scalar),
+            product.u.unwrap()
+        );
+        assert!(curve.ladder(base.u.as_ref().unwrap(), &order).is_zero());
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert!(MontgomeryCurve::new(2, 1, 101).is_err());
+        assert!(MontgomeryCurve::new(-2, 1, 101).is_err());
+        assert!(MontgomeryCurve::new(5, 0, 101).is_err());
+        assert!(MontgomeryCurve::new(5, 1, 64).is_err());
+        assert!(MontgomeryCurve::new(5, 3, 101)
+            .unwrap()
+            .point(1, 1)
+            .is_err());
+        assert!(MontgomeryCurve::new(0, 1, 3)
+            .unwrap()
+            .to_weierstrass()
+            .is_err());
+    }
+}
@@ -0,0 +1,275 @@
+//! 標準化された名前付き曲線
+//!
+//! SEC 2、FIPS 186-4、RFC 5639 で定義された曲線のパラメータを提供する。
+
+use num_bigint::BigUint;
+
+use super::Curve;
+use crate::point::Point;
+
+/// 曲線と、その標準の生成点・位数・余因子
+#[derive(Debug, Clone)]
+pub struct CurveParams {
+    pub name: &'static str,
+    pub oid: &'static str,
+    pub curve: Curve,
+    pub generator: Point,
+    pub order: BigUint,
+    pub cofactor: BigUint,
+}
+
+/// 16進数で記述した曲線パラメータ
+struct CurveSpec {
+    /// 先頭が正式名、残りは別名
+    names: &'static [&'static str],
+    oid: &'static str,
+    p: &'static str,
+    a: &'static str,
+    b: &'static str,
+    gx: &'static str,
+    gy: &'static str,
+    n: &'static str,
+    h: u32,
+}
+
+/// secp256k1 (SEC 2)
+const SECP256K1: CurveSpec = CurveSpec {
+    names: &["secp256k1"],
+    oid: "1.3.132.0.10",
+    p: "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFC2F",
+    a: "0",
+    b: "7",
+    gx: "79BE667EF9DCBBAC55A06295CE870B07029BFCDB2DCE28D959F2815B16F81798",
+    gy: "483ADA7726A3C4655DA4FBFC0E1108A8FD17B448A68554199C47D08FFB10D4B8",
+    n: "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEBAAEDCE6AF48A03BBFD25E8CD0364141",
+    h: 1,
+};
+
+/// P-256 (FIPS 186-4)
+const P256: CurveSpec = CurveSpec {
+    names: &["P-256", "secp256r1", "prime256v1"],
+    oid: "1.2.840.10045.3.1.7",
+    p: "FFFFFFFF00000001000000000000000000000000FFFFFFFFFFFFFFFFFFFFFFFF",
+    a: "FFFFFFFF00000001000000000000000000000000FFFFFFFFFFFFFFFFFFFFFFFC",
+    b: "5AC635D8AA3A93E7B3EBBD55769886BC651D06B0CC53B0F63BCE3C3E27D2604B",
+    gx: "6B17D1F2E12C4247F8BCE6E563A440F277037D812DEB33A0F4A13945D898C296",
+    gy: "4FE342E2FE1A7F9B8EE7EB4A7C0F9E162BCE33576B315ECECBB6406837BF51F5",
+    n: "FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632551",
+    h: 1,
+};
+
+/// P-384 (FIPS 186-4)
+const P384: CurveSpec = CurveSpec {
+    names: &["P-384", "secp384r1"],
+    oid: "1.3.132.0.34",
+    p: "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFFFF0000000000000000FFFFFFFF",
+    a: "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFEFFFFFFFF0000000000000000FFFFFFFC",
+    b: "B3312FA7E23EE7E4988E056BE3F82D19181D9C6EFE8141120314088F5013875AC656398D8A2ED19D2A85C8EDD3EC2AEF",
+    gx: "AA87CA22BE8B05378EB1C71EF320AD746E1D3B628BA79B9859F741E082542A385502F25DBF55296C3A545E3872760AB7",
+    gy: "3617DE4A96262C6F5D9E98BF9292DC29F8F41DBD289A147CE9DA3113B5F0B8C00A60B1CE1D7E819D7A431D7C90EA0E5F",
+    n: "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFC7634D81F4372DDF581A0DB248B0A77AECEC196ACCC52973",
+    h: 1,
+};
+
+/// P-521 (FIPS 186-4)
+const P521: CurveSpec = CurveSpec {
+    names: &["P-521", "secp521r1"],
+    oid: "1.3.132.0.35",
+    p: "1FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF",
+    a: "1FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFC",
+    b: "51953EB9618E1C9A1F929A21A0B68540EEA2DA725B99B315F3B8B489918EF109E156193951EC7E937B1652C0BD3BB1BF073573DF883D2C34F1EF451FD46B503F00",
+    gx: "C6858E06B70404E9CD9E3ECB662395B4429C648139053FB521F828AF606B4D3DBAA14B5E77EFE75928FE1DC127A2FFA8DE3348B3C1856A429BF97E7E31C2E5BD66",
+    gy: "11839296A789A3BC0045C8A5FB42C7D1BD998F54449579B446817AFBD17273E662C97EE72995EF42640C550B9013FAD0761353C7086A272C24088BE94769FD16650",
+    n: "1FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFA51868783BF2F966B7FCC0148F709A5D03BB5C9B8899C47AEBB6FB71E91386409",
+    h: 1,
+};
+
+/// brainpoolP256r1 (RFC 5639)
+const BRAINPOOL_P256R1: CurveSpec = CurveSpec {
+    names: &["brainpoolP256r1"],
+    oid: "1.3.36.3.3.2.8.1.1.7",
+    p: "A9FB57DBA1EEA9BC3E660A909D838D726E3BF623D52620282013481D1F6E5377",
+    a: "7D5A0975FC2C3057EEF67530417AFFE7FB8055C126DC5C6CE94A4B44F330B5D9",
+    b: "26DC5C6CE94A4B44F330B5D9BBD77CBF958416295CF7E1CE6BCCDC18FF8C07B6",
+    gx: "8BD2AEB9CB7E57CB2C4B482FFC81B7AFB9DE27E1E3BD23C23A4453BD9ACE3262",
+    gy: "547EF835C3DAC4FD97F8461A14611DC9C27745132DED8E545C1D54C72F046997",
+    n: "A9FB57DBA1EEA9BC3E660A909D838D718C397AA3B561A6F7901E0E82974856A7",
+    h: 1,
+};
+
+/// brainpoolP384r1 (RFC 5639)
+const BRAINPOOL_P384R1: CurveSpec = CurveSpec {
+    names: &["brainpoolP384r1"],
+    oid: "1.3.36.3.3.2.8.1.1.11",
+    p: "8CB91E82A3386D280F5D6F7E50E641DF152F7109ED5456B412B1DA197FB71123ACD3A729901D1A71874700133107EC53",
+    a: "7BC382C63D8C150C3C72080ACE05AFA0C2BEA28E4FB22787139165EFBA91F90F8AA5814A503AD4EB04A8C7DD22CE2826",
+    b: "4A8C7DD22CE28268B39B55416F0447C2FB77DE107DCD2A62E880EA53EEB62D57CB4390295DBC9943AB78696FA504C11",
+    gx: "1D1C64F068CF45FFA2A63A81B7C13F6B8847A3E77EF14FE3DB7FCAFE0CBD10E8E826E03436D646AAEF87B2E247D4AF1E",
+    gy: "8ABE1D7520F9C2A45CB1EB8E95CFD55262B70B29FEEC5864E19C054FF99129280E4646217791811142820341263C5315",
+    n: "8CB91E82A3386D280F5D6F7E50E641DF152F7109ED5456B31F166E6CAC0425A7CF3AB6AF6B7FC3103B883202E9046565",
+    h: 1,
+};
+
+/// brainpoolP512r1 (RFC 5639)
+const BRAINPOOL_P512R1: CurveSpec = CurveSpec {
+    names: &["brainpoolP512r1"],
+    oid: "1.3.36.3.3.2.8.1.1.13",
+    p: "AADD9DB8DBE9C48B3FD4E6AE33C9FC07CB308DB3B3C9D20ED6639CCA703308717D4D9B009BC66842AECDA12AE6A380E62881FF2F2D82C68528AA6056583A48F3",
+    a: "7830A3318B603B89E2327145AC234CC594CBDD8D3DF91610A83441CAEA9863BC2DED5D5AA8253AA10A2EF1C98B9AC8B57F1117A72BF2C7B9E7C1AC4D77FC94CA",
+    b: "3DF91610A83441CAEA9863BC2DED5D5AA8253AA10A2EF1C98B9AC8B57F1117A72BF2C7B9E7C1AC4D77FC94CADC083E67984050B75EBAE5DD2809BD638016F723",
+    gx: "81AEE4BDD82ED9645A21322E9C4C6A9385ED9F70B5D916C1B43B62EEF4D0098EFF3B1F78E2D0D48D50D1687B93B97D5F7C6D5047406A5E688B352209BCB9F822",
+    gy: "7DDE385D566332ECC0EABFA9CF7822FDF209F70024A57B1AA000C55B881F8111B2DCDE494A5F485E5BCA4BD88A2763AED1CA2B2FA8F0540678CD1E0F3AD80892",
+    n: "AADD9DB8DBE9C48B3FD4E6AE33C9FC07CB308DB3B3C9D20ED6639CCA70330870553E5C414CA92619418661197FAC10471DB1D381085DDADDB58796829CA90069",
+    h: 1,
+};
+
+const SPECS: [&CurveSpec; 7] = [
+    &SECP256K1,
+    &P256,
+    &P384,
+    &P521,
+    &BRAINPOOL_P256R1,
+    &BRAINPOOL_P384R1,
+    &BRAINPOOL_P512R1,
+];
+
+/// 16進数の定数を読み込む
+fn hex(value: &str) -> BigUint {
+    BigUint::parse_bytes(value.as_bytes(), 16).expect("curve constants are valid hex")
+}
+
+/// 曲線パラメータを構築
+fn build(spec: &CurveSpec) -> CurveParams {
+    let curve = Curve::new(hex(spec.a), hex(spec.b), hex(spec.p)).expect("curve is non-singular");
+    let generator = curve
+        .point(hex(spec.gx), hex(spec.gy))
+        .expect("generator lies on the curve");
+    CurveParams {
+        name: spec.names[0],
+        oid: spec.oid,
+        curve,
+        generator,
+        order: hex(spec.n),
+        cofactor: BigUint::from(spec.h),
+    }
+}
+
+/// 名前から曲線を検索（大文字・小文字は区別しない）
+///
+/// `"P-256"`、`"secp256r1"`、`"prime256v1"` のような別名も受け付ける。
+pub fn by_name(name: &str) -> Option<CurveParams> {
+    SPECS
+        .iter()
+        .find(|spec| spec.names.iter().any(|n| n.eq_ignore_ascii_case(name)))
+        .map(|spec| build(spec))
+}
+
+/// OID（ドット区切り）から曲線を検索
+pub fn by_oid(oid: &str) -> Option<CurveParams> {
+    SPECS
+        .iter()
+        .find(|spec| spec.oid == oid)
+        .map(|spec| build(spec))
+}
+
+/// 登録されているすべての曲線の正式名
+pub fn names() -> impl Iterator<Item = &'static str> {
+    SPECS.iter().map(|spec| spec.names[0])
+}
+
+/// 名前付き曲線のコンストラクタを定義
+impl Curve {
+    /// secp256k1 (y² = x³ + 7)
+    pub fn secp256k1() -> CurveParams {
+        build(&SECP256K1)
+    }
+
+    /// NIST P-256 (secp256r1)
+    pub fn p256() -> CurveParams {
+        build(&P256)
+    }
+
+    /// NIST P-384 (secp384r1)
+    pub fn p384() -> CurveParams {
+        build(&P384)
+    }
+
+    /// NIST P-521 (secp521r1)
+    pub fn p521() -> CurveParams {
+        build(&P521)
+    }
+
+    /// brainpoolP256r1
+    pub fn brainpool_p256r1() -> CurveParams {
+        build(&BRAINPOOL_P256R1)
+    }
+
+    /// brainpoolP384r1
+    pub fn brainpool_p384r1() -> CurveParams {
+        build(&BRAINPOOL_P384R1)
+    }
+
+    /// brainpoolP512r1
+    pub fn brainpool_p512r1() -> CurveParams {
+        build(&BRAINPOOL_P512R1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arith::is_probable_prime;
+    use crate::field::FieldElement;
+
+    #[test]
+    fn test_generators_have_stated_order() {
+        for name in names() {
+            let params = by_name(name).unwrap();
+            assert!(is_probable_prime(&params.curve.prime), "{name}");
+            assert!(is_probable_prime(&params.order), "{name}");
+            assert!(
+                params
+                    .generator
+                    .mul_vartime(params.order.clone())
+                    .unwrap()
+                    .is_infinity(),
+                "{name}"
+            );
+            assert!(params.generator.is_in_prime_order_subgroup(&params.order));
+        }
+    }
+
+    #[test]
+    fn test_lookup_by_name_and_oid() {
+        assert_eq!(by_name("secp256r1").unwrap().name, "P-256");
+        assert_eq!(by_name("PRIME256V1").unwrap().name, "P-256");
+        assert_eq!(by_name("secp521r1").unwrap().name, "P-521");
+        assert_eq!(by_oid("1.3.132.0.10").unwrap().name, "secp256k1");
+        assert_eq!(
+            by_oid("1.3.36.3.3.2.8.1.1.7").unwrap().name,
+            "brainpoolP256r1"
+        );
+        assert!(by_name("curve25519").is_none());
+        assert!(by_oid("1.2.3").is_none());
+
+        for name in names() {
+            let params = by_name(name).unwrap();
+            assert_eq!(by_oid(params.oid).unwrap().name, name);
+        }
+    }
+
+    #[test]
+    fn test_constructors() {
+        let secp256k1 = Curve::secp256k1();
+        assert!(secp256k1.curve.a.is_zero());
+        assert_eq!(secp256k1.cofactor, BigUint::from(1u32));
+
+        // NIST曲線は a = -3
+        for params in [Curve::p256(), Curve::p384(), Curve::p521()] {
+            let minus_three = FieldElement::new(-3, params.curve.prime.clone()).unwrap();
+            assert_eq!(params.curve.a, minus_three);
+        }
+        assert_eq!(Curve::p521().curve.prime.bits(), 521);
+        assert_eq!(Curve::brainpool_p512r1().order.bits(), 512);
+    }
+}
@@ -0,0 +1,151 @@
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+use std::collections::HashMap;
+
+use super::{Curve, CurveError};
+use crate::arith::factor;
+use crate::point::Point;
+
+/// 点の位数の計算を定義
+impl Curve {
+    /// 点の位数を計算
+    ///
+    /// Hasseの定理より #E は [p + 1 - 2√p, p + 1 + 2√p] にあるので、
+    /// この区間で M·P = O となる M をbaby-step giant-step (Mestreの方法) で探し、
+    /// M の素因数で割れるだけ割って正確な位数を求める。計算量は O(p^¼)。
+    pub fn point_order(&self, point: &Point) -> Result<BigUint, CurveError> {
+        if point.a != self.a || point.b != self.b {
+            return Err(CurveError::PointGenerationFailed);
+        }
+
+        let multiple = self.order_multiple(point)?;
+
+        let mut order = multiple.clone();
+        for (q, exponent) in factor(&multiple) {
+            for _ in 0..exponent {
+                let candidate = &order / &q;
+                if self.mul_public(point, &candidate)?.is_infinity() {
+                    order = candidate;
+                } else {
+                    break;
+                }
+            }
+        }
+        Ok(order)
+    }
+
+    /// Hasse区間内で M·P = O となる正の整数 M を探す
+    fn order_multiple(&self, point: &Point) -> Result<BigUint, CurveError> {
+        if point.is_infinity() {
+            return Ok(BigUint::one());
+        }
+
+        // Hasse区間 [lower, upper]
+        let width = (&self.prime << 2u32).sqrt();
+        let lower = &self.prime + 1u32 - &width;
+        let upper = &self.prime + 1u32 + &width;
+        let m = (&width << 1u32).sqrt() + 1u32;
+
+        // baby step: jP (1 ≤ j ≤ m)
+        let mut baby_steps = HashMap::new();
+        let mut current = point.clone();
+        let mut j = BigUint::one();
+        while j <= m {
+            if current.is_infinity() {
+                // 位数が m 以下ならそのまま求まる
+                return Ok(j);
+            }
+            baby_steps.insert(current.to_sec1(true), j.clone());
+            current = (&current + point).map_err(|_| CurveError::PointGenerationFailed)?;
+            j += 1u32;
+        }
+
+        // giant step: (lower + im)P と jP の一致を探す
+        let step = self.mul_public(point, &m)?;
+        let mut base = lower.clone();
+        let mut giant = self.mul_public(point, &lower)?;
+        while base <= &upper + &m {
+            if giant.is_infinity() && !base.is_zero() {
+                return Ok(base);
+            }
+            if let Some(j) = baby_steps.get(&giant.to_sec1(true)) {
+                if base > *j {
+                    return Ok(&base - j);
+                }
+            }
+            giant = (&giant + &step).map_err(|_| CurveError::PointGenerationFailed)?;
+            base += &m;
+        }
+
+        Err(CurveError::PointGenerationFailed)
+    }
+
+    /// 公開スカラーによる倍算
+    pub(super) fn mul_public(&self, point: &Point, scalar: &BigUint) -> Result<Point, CurveError> {
+        point
+            .mul_vartime(scalar.clone())
+            .map_err(|_| CurveError::PointGenerationFailed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 加算を繰り返して位数を求める
+    fn naive_order(point: &Point) -> BigUint {
+        let mut current = point.clone();
+        let mut n = BigUint::one();
+        while !current.is_infinity() {
+            current = (&current + point).unwrap();
+            n += 1u32;
+        }
+        n
+    }
+
+    #[test]
+    fn test_point_order_matches_naive() {
+        let curve = Curve::new(0, 7, 223).unwrap();
+        for (x, y) in [(192, 105), (15, 86), (17, 56), (47, 71), (143, 98)] {
+            let point = curve.point(x, y).unwrap();
+            assert_eq!(curve.point_order(&point).unwrap(), naive_order(&point));
+        }
+        assert_eq!(
+            curve.point_order(&curve.infinity_point()).unwrap(),
+            BigUint::one()
+        );
+    }
+
+    #[test]
+    fn test_point_order_all_points_small_curves() {
+        for (a, b, p) in [(2, 3, 97), (-1, 0, 23), (1, 1, 5), (3, 8, 13)] {
+            let curve = Curve::new(a, b, p).unwrap();
+            for x in 0..p {
+                for point in curve.points_with_x(x).unwrap_or_default() {
+                    assert_eq!(curve.point_order(&point).unwrap(), naive_order(&point));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_point_order_large_prime() {
+        // p = 2⁴⁰ + 15 のように総当たりでは時間のかかる大きさでも計算できる
+        let prime = (BigUint::one() << 40) + 15u32;
+        let curve = Curve::new(3, 7, prime).unwrap();
+        let point = (0u32..).find_map(|x| curve.lift_x(x, false).ok()).unwrap();
+
+        let order = curve.point_order(&point).unwrap();
+        assert!((point.clone() * &order).unwrap().is_infinity());
+        for (q, _) in factor(&order) {
+            assert!(!(point.clone() * &(&order / &q)).unwrap().is_infinity());
+        }
+    }
+
+    #[test]
+    fn test_point_order_other_curve() {
+        let curve = Curve::new(0, 7, 223).unwrap();
+        let other = Curve::new(1, 7, 223).unwrap();
+        assert!(curve.point_order(&other.infinity_point()).is_err());
+    }
+}
@@ -0,0 +1,89 @@
+use num_bigint::BigUint;
+use num_traits::Zero;
+use std::collections::HashMap;
+
+use super::{Curve, CurveError};
+use crate::point::Point;
+
+/// 点の列挙を定義
+impl Curve {
+    /// 曲線上のすべての点を列挙
+    ///
+    /// 最初に無限遠点を返し、その後アフィン点を x, y の小さい順に返す。
+    /// 計算量は O(p) なので小さな素数の曲線向け。
+    pub fn points(&self) -> impl Iterator<Item = Point> + '_ {
+        let prime = self.prime.clone();
+        let xs = std::iter::successors(Some(BigUint::zero()), |x| Some(x + 1u32))
+            .take_while(move |x| *x < prime);
+        std::iter::once(self.infinity_point())
+            .chain(xs.flat_map(move |x| self.points_with_x(x).unwrap_or_default()))
+    }
+
+    /// 群の加法表（Cayley表）を計算
+    ///
+    /// `table[i][j]` は `points()` の i 番目と j 番目の点の和の添字。
+    /// 添字 0 は無限遠点（単位元）になる。
+    pub fn addition_table(&self) -> Result<Vec<Vec<usize>>, CurveError> {
+        let points: Vec<Point> = self.points().collect();
+        let index: HashMap<Vec<u8>, usize> = points
+            .iter()
+            .enumerate()
+            .map(|(i, point)| (point.to_sec1(true), i))
+            .collect();
+
+        let mut table = vec![vec![0; points.len()]; points.len()];
+        for (i, p) in points.iter().enumerate() {
+            for (j, q) in points.iter().enumerate().skip(i) {
+                let sum = (p + q).map_err(|_| CurveError::PointGenerationFailed)?;
+                let k = index[&sum.to_sec1(true)];
+                table[i][j] = k;
+                table[j][i] = k;
+            }
+        }
+        Ok(table)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_points_enumeration() {
+        let curve = Curve::new(0, 7, 223).unwrap();
+        let points: Vec<Point> = curve.points().collect();
+        assert_eq!(BigUint::from(points.len()), curve.order().unwrap());
+        assert!(points[0].is_infinity());
+        assert!(points.contains(&curve.point(192, 105).unwrap()));
+
+        let distinct: HashSet<Vec<u8>> = points.iter().map(|p| p.to_sec1(false)).collect();
+        assert_eq!(distinct.len(), points.len());
+    }
+
+    #[test]
+    fn test_addition_table() {
+        let curve = Curve::new(2, 3, 97).unwrap();
+        let points: Vec<Point> = curve.points().collect();
+        let table = curve.addition_table().unwrap();
+        let n = points.len();
+
+        for (i, row) in table.iter().enumerate() {
+            // 単位元と交換法則
+            assert_eq!(table[0][i], i);
+            assert_eq!(row[0], i);
+            for (j, &sum) in row.iter().enumerate() {
+                assert_eq!(sum, table[j][i]);
+            }
+            // 各行は置換になる（ラテン方陣）
+            let distinct: HashSet<usize> = row.iter().copied().collect();
+            assert_eq!(distinct.len(), n);
+        }
+
+        // 表の値が実際の加算と一致し、結合法則を満たす
+        for (i, j, k) in [(1, 2, 3), (5, 5, 7), (10, 20, 30), (n - 1, n - 2, 1)] {
+            assert_eq!(points[table[i][j]], (&points[i] + &points[j]).unwrap());
+            assert_eq!(table[table[i][j]][k], table[i][table[j][k]]);
+        }
+    }
+}
@@ -0,0 +1,399 @@
+use num_bigint::{BigInt, BigUint};
+use num_integer::Integer;
+use num_traits::{One, ToPrimitive, Zero};
+
+use super::{Curve, CurveError};
+use crate::arith::{crt, is_probable_prime};
+use crate::field::poly::{Poly, PolyRing};
+use crate::field::FieldElement;
+
+/// この値未満の素数ではルジャンドル記号の和で点の個数を数える
+const NAIVE_THRESHOLD: u32 = 1 << 12;
+
+/// y 座標を y·Y(x) の形で持つ、ℓ等分多項式を法とした環上の点（`None` は無限遠点）
+type TorsionPoint = Option<(Poly, Poly)>;
+
+/// F_p[x, y] / (h(x), y² - f(x)) 上の曲線演算
+///
+/// h は ℓ等分多項式 ψ_ℓ またはその因子。逆元が存在しない場合は
+/// h の非自明な因子を `Err` で返し、呼び出し側はその因子で計算をやり直す。
+struct TorsionRing<'a> {
+    ring: &'a PolyRing,
+    modulus: Poly,
+    f: Poly,
+    a: Poly,
+}
+
+impl TorsionRing<'_> {
+    fn add(&self, p1: &TorsionPoint, p2: &TorsionPoint) -> Result<TorsionPoint, Poly> {
+        let ring = self.ring;
+        let ((x1, y1), (x2, y2)) = match (p1, p2) {
+            (None, _) => return Ok(p2.clone()),
+            (_, None) => return Ok(p1.clone()),
+            (Some(p1), Some(p2)) => (p1, p2),
+        };
+
+        if x1 == x2 {
+            if y1 == y2 {
+                return self.double(p1);
+            }
+            if ring.add(y1, y2).is_zero() {
+                return Ok(None);
+            }
+            // 一部の根でのみ P = Q となっている
+            return Err(ring.gcd(&ring.sub(y1, y2), &self.modulus));
+        }
+
+        // λ = y·Λ, Λ = (Y₂ - Y₁) / (X₂ - X₁)
+        let inv = ring.inv_mod(&ring.sub(x2, x1), &self.modulus)?;
+        let lambda = ring.mul_mod(&ring.sub(y2, y1), &inv, &self.modulus);
+        self.finish(&lambda, x1, y1, x2)
+    }
+
+    fn double(&self, point: &TorsionPoint) -> Result<TorsionPoint, Poly> {
+        let ring = self.ring;
+        let (x, y) = match point {
+            Some(point) => point,
+            None => return Ok(None),
+        };
+        if y.is_zero() {
+            return Ok(None);
+        }
+
+        // λ = (3X² + a) / (2yY) = y·Λ, Λ = (3X² + a) / (2fY)
+        let numerator = ring.add(
+            &ring.scale(&ring.mul_mod(x, x, &self.modulus), &BigUint::from(3u32)),
+            &self.a,
+        );
+        let denominator = ring.scale(
+            &ring.mul_mod(&self.f, y, &self.modulus),
+            &BigUint::from(2u32),
+        );
+        let inv = ring.inv_mod(&denominator, &self.modulus)?;
+        let lambda = ring.mul_mod(&numerator, &inv, &self.modulus);
+        self.finish(&lambda, x, y, x)
+    }
+
+    /// X₃ = fΛ² - X₁ - X₂, Y₃ = Λ(X₁ - X₃) - Y₁
+    fn finish(&self, lambda: &Poly, x1: &Poly, y1: &Poly, x2: &Poly) -> Result<TorsionPoint, Poly> {
+        let ring = self.ring;
+        let lambda_squared = ring.mul_mod(lambda, lambda, &self.modulus);
+        let x3 = ring.sub(
+            &ring.sub(&ring.mul_mod(&self.f, &lambda_squared, &self.modulus), x1),
+            x2,
+        );
+        let y3 = ring.sub(&ring.mul_mod(lambda, &ring.sub(x1, &x3), &self.modulus), y1);
+        Ok(Some((x3, y3)))
+    }
+
+    fn mul(&self, point: &TorsionPoint, k: u64) -> Result<TorsionPoint, Poly> {
+        let mut result = None;
+        for bit in (0..u64::BITS - k.leading_zeros()).rev() {
+            result = self.double(&result)?;
+            if (k >> bit) & 1 == 1 {
+                result = self.add(&result, point)?;
+            }
+        }
+        Ok(result)
+    }
+}
+
+/// 群の位数の計算を定義
+impl Curve {
+    /// 曲線上の点の個数 #E(F_p) を計算
+    ///
+    /// 小さな素数ではルジャンドル記号の和 p + 1 + Σ(x³ + ax + b / p) を、
+    /// それ以外ではSchoofのアルゴリズムでフロベニウスのトレースを求める。
+    pub fn order(&self) -> Result<BigUint, CurveError> {
+        let trace = self.trace_of_frobenius()?;
+        let order: BigInt = BigInt::from(self.prime.clone()) + 1 - trace;
+        Ok(order.magnitude().clone())
+    }
+
+    /// フロベニウス写像のトレース t = p + 1 - #E(F_p) を計算
+    pub fn trace_of_frobenius(&self) -> Result<BigInt, CurveError> {
+        if !is_probable_prime(&self.prime) {
+            return Err(CurveError::InvalidParameters);
+        }
+        if self.prime < BigUint::from(NAIVE_THRESHOLD) {
+            Ok(self.trace_naive())
+        } else {
+            self.trace_schoof()
+        }
+    }
+
+    /// ルジャンドル記号の和によるトレースの計算
+    fn trace_naive(&self) -> BigInt {
+        // F₂ では各 x に対して y がちょうど1つ定まる
+        if self.prime == BigUint::from(2u32) {
+            return BigInt::zero();
+        }
+
+        let p = self.prime.to_u64().expect("small prime fits in u64");
+        let sum: i64 = (0..p)
+            .map(|x| {
+                let x = FieldElement::new(x, self.prime.clone()).expect("prime is valid");
+                self.rhs(&x).legendre() as i64
+            })
+            .sum();
+        BigInt::from(-sum)
+    }
+
+    /// Schoofのアルゴリズムによるトレースの計算
+    fn trace_schoof(&self) -> Result<BigInt, CurveError> {
+        let ring = PolyRing::new(self.prime.clone());
+
+        // 小さな素数 ℓ について t mod ℓ を求め、積が 4√p を超えたら中国剰余定理で復元する
+        let bound = &self.prime << 4u32;
+        let mut modulus = BigUint::from(2u32);
+        let mut trace = BigUint::from(self.trace_mod_2(&ring));
+
+        let mut l = 3u64;
+        while &modulus * &modulus <= bound {
+            if is_probable_prime(&BigUint::from(l)) {
+                let t = self.trace_mod_l(&ring, l)?;
+                trace = crt(&trace, &modulus, &BigUint::from(t), &BigUint::from(l));
+                modulus *= l;
+            }
+            l += 2;
+        }
+
+        // |t| ≤ 2√p なので対称な剰余系から選ぶ
+        let half = &modulus >> 1u32;
+        let trace = BigInt::from(trace);
+        Ok(if trace > BigInt::from(half) {
+            trace - BigInt::from(modulus)
+        } else {
+            trace
+        })
+    }
+
+    /// t mod 2: x³ + ax + b が F_p に根を持つ（2等分点が存在する）とき t は偶数
+    fn trace_mod_2(&self, ring: &PolyRing) -> u64 {
+        let f = self.rhs_poly(ring);
+        let x = ring.x();
+        let xp = ring.pow_mod(&x, &self.prime, &f);
+        let g = ring.gcd(&ring.sub(&xp, &x), &f);
+        if g.degree() == Some(0) {
+            1
+        } else {
+            0
+        }
+    }
+
+    /// 奇素数 ℓ に対して t mod ℓ を計算
+    fn trace_mod_l(&self, ring: &PolyRing, l: u64) -> Result<u64, CurveError> {
+        let mut modulus = ring.monic(&self.division_polynomial(ring, l));
+        loop {
+            match self.trace_mod_l_with(ring, l, &modulus) {
+                Ok(t) => return t.ok_or(CurveError::InvalidParameters),
+                // ψ_ℓ の因子が見つかったので、その因子を法としてやり直す
+                Err(factor) if factor.degree() > Some(0) && factor != modulus => {
+                    modulus = factor;
+                }
+                Err(_) => return Err(CurveError::InvalidParameters),
+            }
+        }
+    }
+
+    /// π² - tπ + p = 0 を h を法として確かめ、t mod ℓ を探す
+    fn trace_mod_l_with(&self, ring: &PolyRing, l: u64, h: &Poly) -> Result<Option<u64>, Poly> {
+        let p = &self.prime;
+        let f = ring.rem(&self.rhs_poly(ring), h);
+        let torsion = TorsionRing {
+            ring,
+            modulus: h.clone(),
+            f: f.clone(),
+            a: ring.constant(self.a.value().clone()),
+        };
+
+        // π(P) = (x^p, y·f^((p-1)/2)), π²(P) = (x^(p²), y·f^((p²-1)/2))
+        let x = ring.rem(&ring.x(), h);
+        let xp = ring.pow_mod(&x, p, h);
+        let yp = ring.pow_mod(&f, &((p - 1u32) >> 1u32), h);
+        let xp2 = ring.pow_mod(&xp, p, h);
+        let yp2 = ring.pow_mod(&f, &((p * p - 1u32) >> 1u32), h);
+
+        // π²(P) + [p mod ℓ]P
+        let generic = Some((x, ring.constant(1)));
+        let p_mod_l = (p % l).to_u64().expect("p mod l fits in u64");
+        let q_point = torsion.mul(&generic, p_mod_l)?;
+        let (sx, sy) = match torsion.add(&Some((xp2, yp2)), &q_point)? {
+            Some(point) => point,
+            None => return Ok(Some(0)),
+        };
+
+        // [τ]π(P) と比較し、x 座標が一致すれば y 座標の符号で ±τ を決める
+        let frobenius = Some((xp, yp));
+        let mut multiple = frobenius.clone();
+        for tau in 1..=(l - 1) / 2 {
+            if let Some((tx, ty)) = &multiple {
+                let g = ring.gcd(&ring.sub(&sx, tx), h);
+                if g == *h {
+                    if sy == *ty {
+                        return Ok(Some(tau));
+                    }
+                    if ring.add(&sy, ty).is_zero() {
+                        return Ok(Some(l - tau));
+                    }
+                    return Err(ring.gcd(&ring.sub(&sy, ty), h));
+                }
+                if g.degree() > Some(0) {
+                    return Err(g);
+                }
+            }
+            multiple = torsion.add(&multiple, &frobenius)?;
+        }
+
+        Ok(None)
+    }
+
+    /// f(x) = x³ + ax + b
+    fn rhs_poly(&self, ring: &PolyRing) -> Poly {
+        ring.poly(&[
+            BigInt::from(self.b.value().clone()),
+            BigInt::from(self.a.value().clone()),
+            BigInt::zero(),
+            BigInt::one(),
+        ])
+    }
+
+    /// n 等分多項式を計算
+    ///
+    /// n が奇数なら ψ_n、偶数なら ψ_n / y を返す（y を含まない x の多項式）。
+    fn division_polynomial(&self, ring: &PolyRing, n: u64) -> Poly {
+        let a = BigInt::from(self.a.value().clone());
+        let b = BigInt::from(self.b.value().clone());
+        let f = self.rhs_poly(ring);
+        let f_squared = ring.mul(&f, &f);
+        let half = (&self.prime + 1u32) >> 1u32;
+
+        let mut psi = vec![
+            Poly::zero(),
+            ring.constant(1),
+            ring.constant(2),
+            // ψ₃ = 3x⁴ + 6ax² + 12bx - a²
+            ring.poly(&[-&a * &a, &b * 12, &a * 6, BigInt::zero(), BigInt::from(3)]),
+            // ψ₄ / y = 4(x⁶ + 5ax⁴ + 20bx³ - 5a²x² - 4abx - 8b² - a³)
+            ring.poly(&[
+                (-&b * &b * 8 - &a * &a * &a) * 4,
+                -&a * &b * 16,
+                -&a * &a * 20,
+                &b * 80,
+                &a * 20,
+                BigInt::zero(),
+                BigInt::from(4),
+            ]),
+        ];
+
+        let cube = |p: &Poly| ring.mul(&ring.mul(p, p), p);
+        for k in 5..=n as usize {
+            let m = k / 2;
+            let next = if k.is_odd() {
+                // ψ_{2m+1} = ψ_{m+2}ψ_m³ - ψ_{m-1}ψ_{m+1}³ （偶数番目の y² を f に置き換える）
+                let first = ring.mul(&psi[m + 2], &cube(&psi[m]));
+                let second = ring.mul(&psi[m - 1], &cube(&psi[m + 1]));
+                if m.is_even() {
+                    ring.sub(&ring.mul(&f_squared, &first), &second)
+                } else {
+                    ring.sub(&first, &ring.mul(&f_squared, &second))
+                }
+            } else {
+                // ψ_{2m} = ψ_m(ψ_{m+2}ψ_{m-1}² - ψ_{m-2}ψ_{m+1}²) / 2y
+                let first = ring.mul(&psi[m + 2], &ring.mul(&psi[m - 1], &psi[m - 1]));
+                let second = ring.mul(&psi[m - 2], &ring.mul(&psi[m + 1], &psi[m + 1]));
+                ring.scale(&ring.mul(&psi[m], &ring.sub(&first, &second)), &half)
+            };
+            psi.push(next);
+        }
+
+        psi.swap_remove(n as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arith::factor;
+    use crate::point::Point;
+
+    /// Hasse区間内で与えられた数の倍数が1つしかないことを確かめつつ、それを返す
+    fn unique_multiple_in_hasse_interval(curve: &Curve, n: &BigUint) -> BigUint {
+        let width = (&curve.prime << 2u32).sqrt();
+        let lower = &curve.prime + 1u32 - &width;
+        let upper = &curve.prime + 1u32 + &width;
+        let first = lower.div_ceil(n) * n;
+        assert!(first <= upper && &first + n > upper);
+        first
+    }
+
+    #[test]
+    fn test_order_small_curves() {
+        // F223 の y² = x³ + 7 は 252 = 2²·3²·7 個の点を持つ
+        let curve = Curve::new(0, 7, 223).unwrap();
+        assert_eq!(curve.order().unwrap(), BigUint::from(252u32));
+        assert_eq!(curve.trace_of_frobenius().unwrap(), BigInt::from(-28));
+
+        // y² = x³ + x + 1 over F5 は 9 点
+        let curve = Curve::new(1, 1, 5).unwrap();
+        assert_eq!(curve.order().unwrap(), BigUint::from(9u32));
+    }
+
+    #[test]
+    fn test_schoof_matches_naive() {
+        for (a, b, p) in [(2, 3, 4099u32), (0, 7, 4111), (-3, 5, 4129), (1, 0, 4133)] {
+            let curve = Curve::new(a, b, p).unwrap();
+            assert_eq!(
+                curve.trace_schoof().unwrap(),
+                curve.trace_naive(),
+                "a = {a}, b = {b}, p = {p}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_schoof_large_prime() {
+        // p = 2²⁴ - 3 は総当たりで点を列挙するには大きい
+        let prime = BigUint::from((1u32 << 24) - 3);
+        let curve = Curve::new(5, 11, prime).unwrap();
+        let order = curve.order().unwrap();
+
+        // 複数の点の位数の最小公倍数が Hasse 区間内で #E を一意に定める
+        let mut lcm = BigUint::one();
+        for x in 0u32..20 {
+            if let Ok(point) = curve.lift_x(x, false) {
+                assert!((point.clone() * &order).unwrap().is_infinity());
+                lcm = lcm.lcm(&curve.point_order(&point).unwrap());
+            }
+        }
+        assert_eq!(unique_multiple_in_hasse_interval(&curve, &lcm), order);
+        assert!(!factor(&order).is_empty());
+    }
+
+    #[test]
+    fn test_order_rejects_composite_modulus() {
+        let curve = Curve::new(1, 1, 4097).unwrap();
+        assert!(curve.order().is_err());
+    }
+
+    #[test]
+    fn test_division_polynomial_roots_are_torsion_points() {
+        // ψ₅ の根を x 座標に持つ点は 5 等分点
+        let curve = Curve::new(2, 3, 97).unwrap();
+        let ring = PolyRing::new(curve.prime.clone());
+        let psi5 = curve.division_polynomial(&ring, 5);
+        assert_eq!(psi5.degree(), Some(12));
+
+        for x in 0u32..97 {
+            // ホーナー法で ψ₅(x) を評価
+            let on_root = (0..=12)
+                .rev()
+                .fold(BigUint::zero(), |acc, i| (acc * x + psi5.coeff(i)) % 97u32)
+                .is_zero();
+            for point in curve.points_with_x(x).unwrap_or_default() {
+                let five_torsion: Point = (point.clone() * 5).unwrap();
+                assert_eq!(five_torsion.is_infinity(), on_root);
+            }
+        }
+    }
+}
@@ -0,0 +1,289 @@
+use num_bigint::BigUint;
+use num_traits::{One, ToPrimitive, Zero};
+
+use super::named::CurveParams;
+use super::{Curve, CurveError};
+use crate::arith::largest_prime_factor;
+use crate::field::FieldElement;
+use crate::point::Point;
+
+/// 埋め込み次数を調べる上限
+const EMBEDDING_DEGREE_BOUND: u64 = 100;
+
+/// MOV/FR攻撃が現実的とみなす埋め込み次数の上限
+const MOV_DEGREE_THRESHOLD: u64 = 20;
+
+/// 曲線パラメータの弱点
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Weakness {
+    /// 生成点の位数の最大素因数が小さい（Pohlig–Hellman法とρ法）
+    SmallPrimeSubgroup,
+    /// #E = p となる曲線（Smartの攻撃）
+    Anomalous,
+    /// 埋め込み次数が小さい（MOV/FR攻撃）
+    SmallEmbeddingDegree(u64),
+    /// ツイストの位数の最大素因数が小さい（不正な曲線攻撃）
+    WeakTwist,
+    /// ツイストの位数を分解できず、不正な曲線攻撃への耐性を確認できない
+    TwistUnverified,
+    /// 生成点の位数を分解できず、部分群の大きさと埋め込み次数を確認できない
+    SubgroupUnverified,
+}
+
+/// 曲線と生成点の安全性の分析結果
+#[derive(Debug, Clone)]
+pub struct SecurityReport {
+    /// #E(F_p)
+    pub curve_order: BigUint,
+    /// 生成点の位数
+    pub generator_order: BigUint,
+    /// 生成点の位数の最大の素因数 ℓ（分解できなかった場合は `None`）
+    pub largest_prime_factor: Option<BigUint>,
+    /// #E = p かどうか
+    pub is_anomalous: bool,
+    /// p^k ≡ 1 (mod ℓ) となる最小の k（上限までに見つからなければ `None`）
+    pub embedding_degree: Option<u64>,
+    /// ツイストの位数 2p + 2 - #E
+    pub twist_order: BigUint,
+    /// ツイストの位数の最大の素因数（分解できなかった場合は `None`）
+    pub twist_largest_prime_factor: Option<BigUint>,
+    /// 判別式 Δ = -16(4a³ + 27b²)
+    pub discriminant: FieldElement,
+    /// j不変量 1728·4a³ / (4a³ + 27b²)
+    pub j_invariant: FieldElement,
+    /// Pollardのρ法に対する安全性のビット数 log₂√(πℓ/4)
+    pub rho_security_bits: Option<f64>,
+    /// ツイストのρ法に対する安全性のビット数
+    pub twist_security_bits: Option<f64>,
+}
+
+/// 分析結果の判定を定義
+impl SecurityReport {
+    /// 要求する安全性のビット数を満たさない弱点を列挙
+    ///
+    /// 生成点やツイストの位数が分解できなかった場合は、安全とも弱いとも判定できないため
+    /// `SubgroupUnverified` や `TwistUnverified` を返す。
+    pub fn weaknesses(&self, min_security_bits: f64) -> Vec<Weakness> {
+        let mut weaknesses = Vec::new();
+        match self.rho_security_bits {
+            Some(bits) if bits < min_security_bits => weaknesses.push(Weakness::SmallPrimeSubgroup),
+            Some(_) => {}
+            None => weaknesses.push(Weakness::SubgroupUnverified),
+        }
+        if self.is_anomalous {
+            weaknesses.push(Weakness::Anomalous);
+        }
+        if let Some(k) = self.embedding_degree.filter(|&k| k <= MOV_DEGREE_THRESHOLD) {
+            weaknesses.push(Weakness::SmallEmbeddingDegree(k));
+        }
+        match self.twist_security_bits {
+            Some(bits) if bits < min_security_bits => weaknesses.push(Weakness::WeakTwist),
+            Some(_) => {}
+            None => weaknesses.push(Weakness::TwistUnverified),
+        }
+        weaknesses
+    }
+
+    /// 弱点が見つからなければ `true`
+    pub fn is_secure(&self, min_security_bits: f64) -> bool {
+        self.weaknesses(min_security_bits).is_empty()
+    }
+}
+
+/// 安全性の分析を定義
+impl Curve {
+    /// 曲線と生成点の安全性を分析
+    ///
+    /// #E は `order()` で、生成点の位数は `point_order()` で計算する。
+    /// 標準曲線のように位数が分かっている場合は `CurveParams::security_report` を使う。
+    pub fn security_report(&self, generator: &Point) -> Result<SecurityReport, CurveError> {
+        let generator_order = self.point_order(generator)?;
+        Ok(self.analyze(self.order()?, generator_order))
+    }
+
+    /// 判別式 Δ = -16(4a³ + 27b²) を計算
+    pub fn discriminant(&self) -> FieldElement {
+        let minus_sixteen = FieldElement::new(-16, self.prime.clone()).expect("prime is valid");
+        minus_sixteen * self.discriminant_factor()
+    }
+
+    /// 4a³ + 27b² を計算
+    fn discriminant_factor(&self) -> FieldElement {
+        let four = FieldElement::new(4, self.prime.clone()).expect("prime is valid");
+        let twenty_seven = FieldElement::new(27, self.prime.clone()).expect("prime is valid");
+        four * &self.a * &self.a * &self.a + twenty_seven * &self.b * &self.b
+    }
+
+    fn analyze(&self, curve_order: BigUint, generator_order: BigUint) -> SecurityReport {
+        // 位数1の生成点は ℓ = 1 として扱い、ρ法の安全性を0ビットとする
+        let largest = if generator_order.is_one() {
+            Some(BigUint::one())
+        } else {
+            largest_prime_factor(&generator_order)
+        };
+
+        let twist_order = (&self.prime + 1u32) * 2u32 - &curve_order;
+        let twist_largest_prime_factor = largest_prime_factor(&twist_order);
+
+        SecurityReport {
+            is_anomalous: curve_order == self.prime,
+            embedding_degree: largest
+                .as_ref()
+                .and_then(|l| embedding_degree(&self.prime, l)),
+            rho_security_bits: largest.as_ref().map(rho_security_bits),
+            twist_security_bits: twist_largest_prime_factor.as_ref().map(rho_security_bits),
+            discriminant: self.discriminant(),
+            j_invariant: self.j_invariant(),
+            curve_order,
+            generator_order,
+            largest_prime_factor: largest,
+            twist_order,
+            twist_largest_prime_factor,
+        }
+    }
+}
+
+/// 標準曲線の分析を定義
+impl CurveParams {
+    /// 規格に記載された位数と余因子を使って安全性を分析
+    pub fn security_report(&self) -> SecurityReport {
+        let curve_order = &self.order * &self.cofactor;
+        self.curve.analyze(curve_order, self.order.clone())
+    }
+}
+
+/// p^k ≡ 1 (mod ℓ) となる最小の k を探す
+fn embedding_degree(prime: &BigUint, l: &BigUint) -> Option<u64> {
+    let p = prime % l;
+    if p.is_zero() || l.is_one() {
+        return None;
+    }
+    let mut power = p.clone();
+    for k in 1..=EMBEDDING_DEGREE_BOUND {
+        if power.is_one() {
+            return Some(k);
+        }
+        power = power * &p % l;
+    }
+    None
+}
+
+/// ρ法の期待計算量 √(πℓ/4) のビット数
+fn rho_security_bits(l: &BigUint) -> f64 {
+    if l.is_one() {
+        return 0.0;
+    }
+    let log2_l = match l.to_f64() {
+        Some(value) if value.is_finite() => value.log2(),
+        _ => l.bits() as f64,
+    };
+    (0.5 * (log2_l + (std::f64::consts::PI / 4.0).log2())).max(0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toy_curve_report() {
+        // y² = x³ + 7 over F223, (15, 86) の位数は7
+        let curve = Curve::new(0, 7, 223).unwrap();
+        let generator = curve.point(15, 86).unwrap();
+        let report = curve.security_report(&generator).unwrap();
+
+        assert_eq!(report.curve_order, BigUint::from(252u32));
+        assert_eq!(report.generator_order, BigUint::from(7u32));
+        assert_eq!(report.largest_prime_factor, Some(BigUint::from(7u32)));
+        assert!(!report.is_anomalous);
+        // 223 ≡ -1 (mod 7)
+        assert_eq!(report.embedding_degree, Some(2));
+        // 2·224 - 252 = 196 = 2²·7²
+        assert_eq!(report.twist_order, BigUint::from(196u32));
+        assert_eq!(report.twist_largest_prime_factor, Some(BigUint::from(7u32)));
+        assert!(report.j_invariant.is_zero());
+        // -16·27·49 mod 223
+        assert_eq!(
+            report.discriminant,
+            FieldElement::new(-16 * 27 * 49, 223).unwrap()
+        );
+
+        assert_eq!(
+            report.weaknesses(80.0),
+            vec![
+                Weakness::SmallPrimeSubgroup,
+                Weakness::SmallEmbeddingDegree(2),
+                Weakness::WeakTwist
+            ]
+        );
+        assert!(!report.is_secure(80.0));
+    }
+
+    #[test]
+    fn test_anomalous_curve() {
+        // y² = x³ + x + 1 over F97 は97個の点を持つ
+        let curve = Curve::new(1, 1, 97).unwrap();
+        let generator = curve.find_generator(&BigUint::from(97u32)).unwrap();
+        let report = curve.security_report(&generator).unwrap();
+        assert!(report.is_anomalous);
+        assert_eq!(report.embedding_degree, None);
+        assert!(report.weaknesses(0.0).contains(&Weakness::Anomalous));
+    }
+
+    #[test]
+    fn test_supersingular_curve_has_embedding_degree_two() {
+        // p ≡ 3 mod 4 のとき y² = x³ + x は #E = p + 1
+        let curve = Curve::new(1, 0, 4091).unwrap();
+        let generator = curve.find_generator(&BigUint::from(31u32)).unwrap();
+        let report = curve.security_report(&generator).unwrap();
+        assert_eq!(report.curve_order, BigUint::from(4092u32));
+        assert_eq!(report.embedding_degree, Some(2));
+        assert_eq!(report.j_invariant, FieldElement::new(1728, 4091).unwrap());
+    }
+
+    #[test]
+    fn test_named_curve_report() {
+        let report = Curve::secp256k1().security_report();
+        assert!(!report.is_anomalous);
+        assert_eq!(report.embedding_degree, None);
+        assert!((report.rho_security_bits.unwrap() - 127.8).abs() < 0.1);
+
+        // secp256k1 のツイストの最大素因数は220ビット
+        let twist_factor = report.twist_largest_prime_factor.clone().unwrap();
+        assert_eq!(twist_factor.bits(), 220);
+        assert!(report.is_secure(100.0));
+        assert_eq!(report.weaknesses(120.0), vec![Weakness::WeakTwist]);
+
+        let report = Curve::p256().security_report();
+        assert!(report.is_secure(112.0));
+    }
+
+    #[test]
+    fn test_unfactorable_generator_order_is_unverified() {
+        // 大きな素因数を2つ持つ位数を与えても、分解を打ち切って結果を返す
+        let params = Curve::secp256k1();
+        let generator_order =
+            ((BigUint::one() << 127u32) - 1u32) * ((BigUint::one() << 89u32) - 1u32);
+        let curve_order = &params.order * &params.cofactor;
+        let report = params.curve.analyze(curve_order, generator_order);
+
+        assert_eq!(report.largest_prime_factor, None);
+        assert_eq!(report.rho_security_bits, None);
+        assert_eq!(report.embedding_degree, None);
+        assert_eq!(report.weaknesses(100.0), vec![Weakness::SubgroupUnverified]);
+    }
+
+    #[test]
+    fn test_unfactorable_twist_is_unverified() {
+        // ツイストの位数が (2¹²⁷ - 1)(2⁸⁹ - 1) となるよう #E を与える
+        let params = Curve::secp256k1();
+        let twist_order = ((BigUint::one() << 127u32) - 1u32) * ((BigUint::one() << 89u32) - 1u32);
+        let curve_order = (&params.curve.prime + 1u32) * 2u32 - &twist_order;
+        let report = params.curve.analyze(curve_order, params.order.clone());
+
+        assert_eq!(report.twist_order, twist_order);
+        assert_eq!(report.twist_largest_prime_factor, None);
+        assert_eq!(report.twist_security_bits, None);
+        assert_eq!(report.weaknesses(100.0), vec![Weakness::TwistUnverified]);
+        assert!(!report.is_secure(0.0));
+    }
+}
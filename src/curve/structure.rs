@@ -0,0 +1,413 @@
+use num_bigint::BigUint;
+use num_integer::Integer;
+use num_traits::{One, Zero};
+use std::collections::HashMap;
+
+use super::{Curve, CurveError};
+use crate::arith::{crt, factor};
+use crate::field::FieldElement;
+use crate::point::Point;
+
+/// 群構造 E(F_p) ≅ Z/n₁ × Z/n₂ (n₂ | n₁)
+#[derive(Debug, Clone)]
+pub struct GroupStructure {
+    /// 群の指数（点の位数の最大値）
+    pub n1: BigUint,
+    /// n₂ = #E / n₁
+    pub n2: BigUint,
+    /// 位数 n₁ の点 P と位数 n₂ の点 Q で、E(F_p) = ⟨P⟩ ⊕ ⟨Q⟩ を満たす
+    pub basis: (Point, Point),
+}
+
+/// 群構造の計算を定義
+impl Curve {
+    /// 群構造 E(F_p) ≅ Z/n₁ × Z/n₂ と、その基底を計算
+    ///
+    /// 点を順に取り出して位数の最小公倍数を持つ点 P を作る。n₂ = #E / n₁ とし、
+    /// 別の点 R から P 成分を取り除いた Q ∈ E[n₂] について、Weilペアリング
+    /// e_{n₂}((n₁/n₂)P, Q) が1の原始 n₂ 乗根になれば、P と Q は独立な基底になる。
+    pub fn group_structure(&self) -> Result<GroupStructure, CurveError> {
+        let order = self.order()?;
+        let infinity = self.infinity_point();
+        if order.is_one() {
+            return Ok(GroupStructure {
+                n1: BigUint::one(),
+                n2: BigUint::one(),
+                basis: (infinity.clone(), infinity),
+            });
+        }
+
+        let mut p = infinity.clone();
+        let mut n1 = BigUint::one();
+
+        for candidate in self.points().skip(1) {
+            // 位数の最小公倍数を持つ点に更新
+            let candidate_order = self.point_order(&candidate)?;
+            if !n1.is_multiple_of(&candidate_order) {
+                (p, n1) = self.combine_orders(&p, &n1, &candidate, &candidate_order)?;
+            }
+
+            if n1 == order {
+                return Ok(GroupStructure {
+                    n1,
+                    n2: BigUint::one(),
+                    basis: (p, infinity),
+                });
+            }
+
+            let n2 = &order / &n1;
+            if !n1.is_multiple_of(&n2) {
+                continue;
+            }
+
+            // n₂R = k·n₂P となる k を求め、Q = R - kP ∈ E[n₂] とする
+            let cofactor = &n1 / &n2;
+            let Some(k) = self.discrete_log(
+                &self.mul_public(&p, &n2)?,
+                &self.mul_public(&candidate, &n2)?,
+                &cofactor,
+            )?
+            else {
+                continue;
+            };
+            let q = add_points(&candidate, &-self.mul_public(&p, &k)?)?;
+
+            // Q が ⟨P⟩ と独立か、Weilペアリングで確かめる
+            let p2 = self.mul_public(&p, &cofactor)?;
+            if let Some(value) = self.try_weil_pairing(&p2, &q, &n2)? {
+                if is_primitive_root_of_unity(&value, &n2) {
+                    return Ok(GroupStructure {
+                        n1,
+                        n2,
+                        basis: (p, q),
+                    });
+                }
+            }
+        }
+
+        Err(CurveError::PointGenerationFailed)
+    }
+
+    /// 群が巡回群かどうかを判定
+    pub fn is_cyclic(&self) -> Result<bool, CurveError> {
+        Ok(self.group_structure()?.n2.is_one())
+    }
+
+    /// Weilペアリング e_n(P, Q) を計算
+    ///
+    /// P, Q は n 等分点でなければならない。結果は F_p の1の n 乗根になる。
+    pub fn weil_pairing(
+        &self,
+        p: &Point,
+        q: &Point,
+        n: &BigUint,
+    ) -> Result<FieldElement, CurveError> {
+        for point in [p, q] {
+            if point.a != self.a || point.b != self.b || !self.mul_public(point, n)?.is_infinity() {
+                return Err(CurveError::InvalidParameters);
+            }
+        }
+        self.try_weil_pairing(p, q, n)?
+            .ok_or(CurveError::PointGenerationFailed)
+    }
+
+    /// e_n(P, Q) = (f_P(Q + S) / f_P(S)) / (f_Q(P - S) / f_Q(-S))
+    ///
+    /// 因子の台が重ならない補助点 S を順に試す。見つからなければ `None` を返す。
+    fn try_weil_pairing(
+        &self,
+        p: &Point,
+        q: &Point,
+        n: &BigUint,
+    ) -> Result<Option<FieldElement>, CurveError> {
+        let one = FieldElement::new(1, self.prime.clone()).expect("prime is valid");
+        if p.is_infinity() || q.is_infinity() || p == q {
+            return Ok(Some(one));
+        }
+
+        for s in self.points().skip(1) {
+            let q_plus_s = add_points(q, &s)?;
+            let p_minus_s = add_points(p, &-s.clone())?;
+            let neg_s = -s.clone();
+
+            let values = [
+                self.miller(p, n, &q_plus_s),
+                self.miller(p, n, &s),
+                self.miller(q, n, &p_minus_s),
+                self.miller(q, n, &neg_s),
+            ];
+            if let [Some(a), Some(b), Some(c), Some(d)] = values {
+                return Ok(Some((a * d) / (b * c)));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Millerのアルゴリズムで div(f) = n(P) - n(O) となる f の R における値を計算
+    ///
+    /// R が途中の直線の零点や極になった場合は `None` を返す。
+    fn miller(&self, p: &Point, n: &BigUint, r: &Point) -> Option<FieldElement> {
+        let mut f = FieldElement::new(1, self.prime.clone()).ok()?;
+        let mut t = p.clone();
+
+        for bit in (0..n.bits().saturating_sub(1)).rev() {
+            let g = self.line_ratio(&t, &t, r)?;
+            f = &f * &f * g;
+            t = (&t + &t).ok()?;
+            if n.bit(bit) {
+                let g = self.line_ratio(&t, p, r)?;
+                f = f * g;
+                t = (&t + p).ok()?;
+            }
+        }
+        Some(f)
+    }
+
+    /// T と U を通る直線を T + U を通る垂直線で割った関数の R における値
+    fn line_ratio(&self, t: &Point, u: &Point, r: &Point) -> Option<FieldElement> {
+        let one = FieldElement::new(1, self.prime.clone()).ok()?;
+        let (xt, yt) = match (&t.x, &t.y) {
+            (Some(x), Some(y)) => (x, y),
+            _ => return Some(one),
+        };
+        let (xu, yu) = match (&u.x, &u.y) {
+            (Some(x), Some(y)) => (x, y),
+            _ => return Some(one),
+        };
+        let (xr, yr) = (r.x.as_ref()?, r.y.as_ref()?);
+
+        // T + U = O なら垂直線 x - x_T のみ
+        if xt == xu && (yt + yu).is_zero() {
+            let value = xr - xt;
+            return (!value.is_zero()).then_some(value);
+        }
+
+        let slope = if xt == xu {
+            let three = FieldElement::new(3, self.prime.clone()).ok()?;
+            (three * xt * xt + &self.a).checked_div(&(yt + yt)).ok()?
+        } else {
+            (yu - yt).checked_div(&(xu - xt)).ok()?
+        };
+        let x_sum = &slope * &slope - xt - xu;
+
+        let line = yr - yt - &slope * (xr - xt);
+        let vertical = xr - x_sum;
+        if line.is_zero() || vertical.is_zero() {
+            return None;
+        }
+        Some(line / vertical)
+    }
+
+    /// 位数 a の点 P と位数 b の点 R から、位数 lcm(a, b) の点を作る
+    fn combine_orders(
+        &self,
+        p: &Point,
+        a: &BigUint,
+        r: &Point,
+        b: &BigUint,
+    ) -> Result<(Point, BigUint), CurveError> {
+        let lcm = a.lcm(b);
+
+        // lcm = a'·b' (a' | a, b' | b, gcd(a', b') = 1) と分ける
+        let mut a_part = BigUint::one();
+        for (q, _) in factor(&lcm) {
+            let mut qa = BigUint::one();
+            while a.is_multiple_of(&(&qa * &q)) {
+                qa *= &q;
+            }
+            let mut qb = BigUint::one();
+            while b.is_multiple_of(&(&qb * &q)) {
+                qb *= &q;
+            }
+            if qa >= qb {
+                a_part *= qa;
+            }
+        }
+        let b_part = &lcm / &a_part;
+
+        let point = add_points(
+            &self.mul_public(p, &(a / &a_part))?,
+            &self.mul_public(r, &(b / &b_part))?,
+        )?;
+        Ok((point, lcm))
+    }
+
+    /// 位数 m の点 G に対して H = kG となる k を Pohlig–Hellman法で求める
+    ///
+    /// H が ⟨G⟩ に含まれなければ `None` を返す。
+    fn discrete_log(
+        &self,
+        g: &Point,
+        h: &Point,
+        m: &BigUint,
+    ) -> Result<Option<BigUint>, CurveError> {
+        let mut k = BigUint::zero();
+        let mut modulus = BigUint::one();
+
+        for (q, exponent) in factor(m) {
+            let q_power = q.pow(exponent);
+            let cofactor = m / &q_power;
+            let g_q = self.mul_public(g, &cofactor)?;
+            let h_q = self.mul_public(h, &cofactor)?;
+            // 位数 q の点
+            let gamma = self.mul_public(&g_q, &q.pow(exponent - 1))?;
+
+            // k mod q^e を q 進数で1桁ずつ求める
+            let mut k_q = BigUint::zero();
+            let mut digit_weight = BigUint::one();
+            for i in 0..exponent {
+                let rest = add_points(&h_q, &-self.mul_public(&g_q, &k_q)?)?;
+                let target = self.mul_public(&rest, &q.pow(exponent - 1 - i))?;
+                let Some(digit) = self.prime_order_log(&gamma, &target, &q)? else {
+                    return Ok(None);
+                };
+                k_q += digit * &digit_weight;
+                digit_weight *= &q;
+            }
+
+            k = crt(&k, &modulus, &k_q, &q_power);
+            modulus *= q_power;
+        }
+
+        // H が ⟨G⟩ に含まれない場合は各桁の解が矛盾する
+        Ok((self.mul_public(g, &k)? == *h).then_some(k))
+    }
+
+    /// 素数位数 q の点 G に対して H = kG となる k をbaby-step giant-stepで求める
+    fn prime_order_log(
+        &self,
+        g: &Point,
+        h: &Point,
+        q: &BigUint,
+    ) -> Result<Option<BigUint>, CurveError> {
+        let m = q.sqrt() + 1u32;
+
+        // baby step: jG (0 ≤ j < m)
+        let mut baby_steps = HashMap::new();
+        let mut current = self.infinity_point();
+        let mut j = BigUint::zero();
+        while j < m {
+            baby_steps.entry(current.to_sec1(true)).or_insert(j.clone());
+            current = add_points(&current, g)?;
+            j += 1u32;
+        }
+
+        // giant step: H - i·mG
+        let step = -self.mul_public(g, &m)?;
+        let mut giant = h.clone();
+        let mut i = BigUint::zero();
+        while i < m {
+            if let Some(j) = baby_steps.get(&giant.to_sec1(true)) {
+                return Ok(Some((&i * &m + j) % q));
+            }
+            giant = add_points(&giant, &step)?;
+            i += 1u32;
+        }
+        Ok(None)
+    }
+}
+
+/// 点の加算の誤りを `CurveError` に変換
+fn add_points(p: &Point, q: &Point) -> Result<Point, CurveError> {
+    (p + q).map_err(|_| CurveError::PointGenerationFailed)
+}
+
+/// ζ が1の原始 n 乗根かどうかを判定
+fn is_primitive_root_of_unity(zeta: &FieldElement, n: &BigUint) -> bool {
+    if !zeta
+        .pow(n.clone())
+        .expect("pow is infallible")
+        .value()
+        .is_one()
+    {
+        return false;
+    }
+    factor(n)
+        .into_iter()
+        .all(|(q, _)| !zeta.pow(n / q).expect("pow is infallible").value().is_one())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    /// 群構造の基底が全体を生成することを確認
+    fn check_structure(curve: &Curve, n1: u32, n2: u32) {
+        let structure = curve.group_structure().unwrap();
+        assert_eq!(structure.n1, BigUint::from(n1));
+        assert_eq!(structure.n2, BigUint::from(n2));
+
+        let (p, q) = &structure.basis;
+        assert_eq!(curve.point_order(p).unwrap(), BigUint::from(n1));
+        assert_eq!(curve.point_order(q).unwrap(), BigUint::from(n2));
+
+        let mut points = HashSet::new();
+        for i in 0..n1 {
+            for j in 0..n2 {
+                let point = (p.mul_vartime(i).unwrap() + q.mul_vartime(j).unwrap()).unwrap();
+                points.insert(point.to_sec1(false));
+            }
+        }
+        assert_eq!(BigUint::from(points.len()), curve.order().unwrap());
+    }
+
+    #[test]
+    fn test_non_cyclic_group() {
+        // y² = x³ + 7 over F223: 252 = 42·6 なので位数42の点が最大
+        let curve = Curve::new(0, 7, 223).unwrap();
+        check_structure(&curve, 42, 6);
+        assert!(!curve.is_cyclic().unwrap());
+    }
+
+    #[test]
+    fn test_cyclic_group() {
+        let curve = Curve::new(1, 1, 5).unwrap();
+        check_structure(&curve, 9, 1);
+        assert!(curve.is_cyclic().unwrap());
+    }
+
+    #[test]
+    fn test_full_two_torsion() {
+        // y² = x³ - x = x(x - 1)(x + 1) は F23 上に2等分点をすべて持つ
+        let curve = Curve::new(-1, 0, 23).unwrap();
+        check_structure(&curve, 12, 2);
+    }
+
+    #[test]
+    fn test_supersingular_structure() {
+        // p ≡ 3 mod 4 のとき y² = x³ - x は #E = p + 1 で、2等分点をすべて持つ
+        let curve = Curve::new(-1, 0, 4091).unwrap();
+        let structure = curve.group_structure().unwrap();
+        assert_eq!(structure.n1, BigUint::from(2046u32));
+        assert_eq!(structure.n2, BigUint::from(2u32));
+        assert_eq!(
+            curve.point_order(&structure.basis.1).unwrap(),
+            BigUint::from(2u32)
+        );
+    }
+
+    #[test]
+    fn test_weil_pairing_properties() {
+        let curve = Curve::new(0, 7, 223).unwrap();
+        let structure = curve.group_structure().unwrap();
+        let n = BigUint::from(6u32);
+        let p = structure.basis.0.mul_vartime(7).unwrap();
+        let q = structure.basis.1.clone();
+
+        let e = curve.weil_pairing(&p, &q, &n).unwrap();
+        // 1の原始6乗根で、交代性 e(P, Q) = e(Q, P)⁻¹ と双線形性を満たす
+        assert!(is_primitive_root_of_unity(&e, &n));
+        assert_eq!(
+            &e * curve.weil_pairing(&q, &p, &n).unwrap(),
+            e.pow(0).unwrap()
+        );
+        let p2 = p.mul_vartime(2).unwrap();
+        assert_eq!(curve.weil_pairing(&p2, &q, &n).unwrap(), e.pow(2).unwrap());
+        assert!(curve.weil_pairing(&p, &p, &n).unwrap().value().is_one());
+
+        // n 等分点でない点は拒否する
+        let generator = curve.point(192, 105).unwrap();
+        assert!(curve.weil_pairing(&generator, &q, &n).is_err());
+    }
+}
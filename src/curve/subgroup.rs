@@ -0,0 +1,96 @@
+use num_bigint::BigUint;
+use num_traits::Zero;
+
+use super::{Curve, CurveError};
+use crate::arith::{factor, is_probable_prime};
+use crate::point::Point;
+
+/// 部分群と生成点の選択を定義
+impl Curve {
+    /// 余因子 h = #E / n を計算（n は #E の最大の素因数）
+    pub fn cofactor(&self) -> Result<BigUint, CurveError> {
+        let order = self.order()?;
+        let largest = factor(&order)
+            .into_iter()
+            .map(|(q, _)| q)
+            .max()
+            .ok_or(CurveError::InvalidParameters)?;
+        Ok(order / largest)
+    }
+
+    /// 位数が素数 `order` の点を決定的に探す
+    ///
+    /// `order` は #E を割り切る素数でなければならない。
+    /// x 座標の小さい順に点 P を取り、#E = qᵉ·m (q ∤ m) として m·P を q 倍していき、
+    /// 無限遠点の直前の点が見つかればそれを返す。
+    pub fn find_generator(&self, order: &BigUint) -> Result<Point, CurveError> {
+        let mut cofactor = self.order()?;
+        if !is_probable_prime(order) || !(&cofactor % order).is_zero() {
+            return Err(CurveError::InvalidParameters);
+        }
+        while (&cofactor % order).is_zero() {
+            cofactor /= order;
+        }
+
+        for point in self.points().skip(1) {
+            let mut candidate = self.mul_public(&point, &cofactor)?;
+            if candidate.is_infinity() {
+                continue;
+            }
+            loop {
+                let next = self.mul_public(&candidate, order)?;
+                if next.is_infinity() {
+                    return Ok(candidate);
+                }
+                candidate = next;
+            }
+        }
+        Err(CurveError::PointGenerationFailed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cofactor() {
+        // 252 = 2²·3²·7
+        let curve = Curve::new(0, 7, 223).unwrap();
+        assert_eq!(curve.cofactor().unwrap(), BigUint::from(36u32));
+
+        // y² = x³ + x + 1 over F5 は位数9
+        let curve = Curve::new(1, 1, 5).unwrap();
+        assert_eq!(curve.cofactor().unwrap(), BigUint::from(3u32));
+    }
+
+    #[test]
+    fn test_find_generator() {
+        let curve = Curve::new(0, 7, 223).unwrap();
+        for q in [2u32, 3, 7] {
+            let q = BigUint::from(q);
+            let generator = curve.find_generator(&q).unwrap();
+            assert_eq!(curve.point_order(&generator).unwrap(), q);
+            // 決定的に同じ点を返す
+            assert_eq!(curve.find_generator(&q).unwrap(), generator);
+        }
+
+        // 素数でない、または #E を割り切らない位数は拒否する
+        for n in [6u32, 5, 1] {
+            assert!(matches!(
+                curve.find_generator(&BigUint::from(n)),
+                Err(CurveError::InvalidParameters)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_find_generator_large_prime() {
+        let curve = Curve::new(0, 7, (1u64 << 20) + 7).unwrap();
+        let order = curve.order().unwrap();
+        let q = &order / curve.cofactor().unwrap();
+        let generator = curve.find_generator(&q).unwrap();
+        assert!(generator.is_in_prime_order_subgroup(&q));
+        assert!(!generator.is_infinity());
+    }
+}
@@ -0,0 +1,123 @@
+use num_bigint::{BigInt, BigUint};
+use num_integer::Integer;
+use num_traits::{One, Zero};
+
+use super::FieldElement;
+
+/// 立方根の計算を定義
+impl FieldElement {
+    /// 立方根を1つ計算
+    ///
+    /// p ≡ 2 (mod 3) では a ↦ a³ が全単射なので a^((2p-1)/3) が唯一の立方根になる。
+    /// p ≡ 1 (mod 3) では立方剰余でなければ `None` を返し、
+    /// 立方剰余なら Tonelli–Shanks と同様に 3-Sylow 部分群で離散対数を求めて計算する。
+    pub fn cbrt(&self) -> Option<Self> {
+        let p = &self.prime;
+        if self.value.is_zero() || *p == BigUint::from(3u32) {
+            // 標数3ではフロベニウス写像により a³ = a
+            return Some(self.clone());
+        }
+
+        let p_minus_one = p - 1u32;
+        if !(&p_minus_one % 3u32).is_zero() {
+            let exp = (p * 2u32 - 1u32) / 3u32;
+            return Some(Self::from_reduced(self.value.modpow(&exp, p), p));
+        }
+
+        // 立方剰余の判定: a^((p-1)/3) = 1
+        let one = Self::from_reduced(BigUint::one(), p);
+        if self.pow(&p_minus_one / 3u32).ok()? != one {
+            return None;
+        }
+
+        // p - 1 = 3^e·m (3 ∤ m)
+        let mut m = p_minus_one.clone();
+        let mut e = 0u32;
+        while (&m % 3u32).is_zero() {
+            m /= 3u32;
+            e += 1;
+        }
+
+        // 立方非剰余 z から位数 3^e の元 g = z^m を作る
+        let mut z = Self::from_reduced(BigUint::from(2u32), p);
+        while z.pow(&p_minus_one / 3u32).ok()? == one {
+            z = &z + &one;
+        }
+        let g = z.pow(m.clone()).ok()?;
+        // 位数3の元
+        let zeta = g.pow(BigUint::from(3u32).pow(e - 1)).ok()?;
+
+        // b = a^m = g^k となる k を3進数で1桁ずつ求める
+        let b = self.pow(m.clone()).ok()?;
+        let mut k = BigUint::zero();
+        let mut weight = BigUint::one();
+        for i in 0..e {
+            let rest = &b * g.pow(-BigInt::from(k.clone())).ok()?;
+            let digit_value = rest.pow(BigUint::from(3u32).pow(e - 1 - i)).ok()?;
+            let digit = if digit_value == one {
+                0u32
+            } else if digit_value == zeta {
+                1
+            } else {
+                2
+            };
+            k += &weight * digit;
+            weight *= 3u32;
+        }
+        // a が立方剰余なので k は3の倍数で、y = g^(k/3) は y³ = b を満たす
+        let y = g.pow(k / 3u32).ok()?;
+
+        // 3α + mβ = 1 とすると a = (a^α)³·b^β = (a^α·y^β)³
+        let gcd = BigInt::from(3).extended_gcd(&BigInt::from(m));
+        let root = self.pow(gcd.x).ok()? * y.pow(gcd.y).ok()?;
+
+        debug_assert_eq!(&root * &root * &root, *self);
+        Some(root)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// すべての元について立方根の有無と値を確かめる
+    fn check_all_elements(prime: u32) {
+        let mut cubes = std::collections::HashSet::new();
+        for x in 0..prime {
+            let x = FieldElement::new(x, prime).unwrap();
+            cubes.insert(&x * &x * &x);
+        }
+
+        for value in 0..prime {
+            let a = FieldElement::new(value, prime).unwrap();
+            match a.cbrt() {
+                Some(root) => assert_eq!(&root * &root * &root, a, "p = {prime}, a = {value}"),
+                None => assert!(!cubes.contains(&a), "p = {prime}, a = {value}"),
+            }
+        }
+        assert_eq!(
+            (0..prime)
+                .filter(|&v| FieldElement::new(v, prime).unwrap().cbrt().is_some())
+                .count(),
+            cubes.len()
+        );
+    }
+
+    #[test]
+    fn test_cbrt_small_primes() {
+        // p ≡ 2 (mod 3)、p ≡ 1 (mod 3) で 3^e || p - 1 の e が異なる場合
+        for prime in [2, 3, 5, 11, 7, 13, 19, 37, 109, 163] {
+            check_all_elements(prime);
+        }
+    }
+
+    #[test]
+    fn test_cbrt_large_prime() {
+        // secp256k1 の素数は p ≡ 1 (mod 3)
+        let prime: BigInt = (BigInt::one() << 256) - (BigInt::one() << 32) - 977;
+        let x = FieldElement::new(123_456_789, prime.clone()).unwrap();
+        let cube = &x * &x * &x;
+        let root = cube.cbrt().unwrap();
+        assert_eq!(&root * &root * &root, cube);
+    }
+}
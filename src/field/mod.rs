@@ -0,0 +1,394 @@
+use num_bigint::{BigInt, BigUint};
+use num_integer::Integer;
+use num_traits::{One, Signed, Zero};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use thiserror::Error;
+
+mod cbrt;
+pub mod montgomery;
+pub(crate) mod poly;
+mod sqrt;
+
+pub use montgomery::{MontgomeryElement, MontgomeryField};
+
+#[derive(Error, Debug)]
+pub enum FieldError {
+    #[error("Invalid field element: value must be between 0 and p-1")]
+    InvalidElement,
+    #[error("Mismatched fields: operations can only be performed on elements of the same field")]
+    MismatchedFields,
+    #[error("Division by zero")]
+    DivisionByZero,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct FieldElement {
+    value: BigUint,
+    prime: BigUint,
+}
+
+/// FieldElementの生成と操作を定義
+impl FieldElement {
+    pub fn new(value: impl Into<BigInt>, prime: impl Into<BigInt>) -> Result<Self, FieldError> {
+        let prime: BigInt = prime.into();
+        if !prime.is_positive() {
+            return Err(FieldError::InvalidElement);
+        }
+        // 値を正規化
+        let normalized_value = value.into().mod_floor(&prime);
+        Ok(Self {
+            value: normalized_value.magnitude().clone(),
+            prime: prime.magnitude().clone(),
+        })
+    }
+
+    /// 正規化済みの値から直接FieldElementを生成
+    fn from_reduced(value: BigUint, prime: &BigUint) -> Self {
+        Self {
+            value,
+            prime: prime.clone(),
+        }
+    }
+
+    /// FieldElementの値を取得
+    pub fn value(&self) -> &BigUint {
+        &self.value
+    }
+
+    /// FieldElementの素数を取得
+    pub fn prime(&self) -> &BigUint {
+        &self.prime
+    }
+
+    /// 体の元を表すのに必要なバイト数
+    pub fn byte_len(&self) -> usize {
+        self.prime.bits().div_ceil(8) as usize
+    }
+
+    /// 体のバイト長に揃えたビッグエンディアン表現を取得
+    pub fn to_bytes_be(&self) -> Vec<u8> {
+        let bytes = self.value.to_bytes_be();
+        let mut padded = vec![0u8; self.byte_len() - bytes.len()];
+        padded.extend_from_slice(&bytes);
+        padded
+    }
+
+    /// 値が0かどうかを判定
+    pub fn is_zero(&self) -> bool {
+        self.value.is_zero()
+    }
+
+    /// べき乗を計算
+    ///
+    /// 2 limbs以上9 limbs以下の奇素数では、Montgomery形式のlimb演算で計算する。
+    pub fn pow(&self, exp: impl Into<BigInt>) -> Result<Self, FieldError> {
+        let exp: BigInt = exp.into();
+        let n = if exp.is_negative() {
+            // Negative exponent: a^(-k) = a^(p-1-k) by Fermat's little theorem
+            let order = BigInt::from(self.prime.clone()) - 1;
+            exp.mod_floor(&order)
+        } else {
+            exp
+        };
+
+        let value = montgomery::pow_mod(&self.value, n.magnitude(), &self.prime)
+            .unwrap_or_else(|| self.value.modpow(n.magnitude(), &self.prime));
+        Ok(Self::from_reduced(value, &self.prime))
+    }
+
+    /// 同じ体に属しているかを確認
+    fn check_same_field(&self, other: &Self) -> Result<(), FieldError> {
+        if self.prime == other.prime {
+            Ok(())
+        } else {
+            Err(FieldError::MismatchedFields)
+        }
+    }
+
+    /// パニックしない加算
+    pub fn checked_add(&self, other: &Self) -> Result<Self, FieldError> {
+        self.check_same_field(other)?;
+        let mut value = &self.value + &other.value;
+        if value >= self.prime {
+            value -= &self.prime;
+        }
+        Ok(Self::from_reduced(value, &self.prime))
+    }
+
+    /// パニックしない減算
+    pub fn checked_sub(&self, other: &Self) -> Result<Self, FieldError> {
+        self.check_same_field(other)?;
+        let value = if self.value >= other.value {
+            &self.value - &other.value
+        } else {
+            &self.value + &self.prime - &other.value
+        };
+        Ok(Self::from_reduced(value, &self.prime))
+    }
+
+    /// パニックしない乗算
+    pub fn checked_mul(&self, other: &Self) -> Result<Self, FieldError> {
+        self.check_same_field(other)?;
+        Ok(Self::from_reduced(
+            (&self.value * &other.value) % &self.prime,
+            &self.prime,
+        ))
+    }
+
+    /// パニックしない除算
+    pub fn checked_div(&self, other: &Self) -> Result<Self, FieldError> {
+        self.check_same_field(other)?;
+        self.checked_mul(&other.try_inv()?)
+    }
+
+    /// 逆元を計算（0の場合は `DivisionByZero` を返す）
+    ///
+    /// Montgomery形式のlimb演算が使える素数ではフェルマーの小定理で、
+    /// それ以外では拡張ユークリッド互除法で計算する。
+    pub fn try_inv(&self) -> Result<Self, FieldError> {
+        if self.value.is_zero() {
+            return Err(FieldError::DivisionByZero);
+        }
+        if let Some(value) = montgomery::inv_mod(&self.value, &self.prime) {
+            return Ok(Self::from_reduced(value, &self.prime));
+        }
+
+        let mut old_r = BigInt::from(self.prime.clone());
+        let mut r = BigInt::from(self.value.clone());
+        let mut old_t = BigInt::zero();
+        let mut t = BigInt::one();
+
+        while !r.is_zero() {
+            let quotient = &old_r / &r;
+            let next_r = &old_r - &quotient * &r;
+            old_r = std::mem::replace(&mut r, next_r);
+            let next_t = &old_t - &quotient * &t;
+            old_t = std::mem::replace(&mut t, next_t);
+        }
+
+        if !old_r.is_one() {
+            return Err(FieldError::InvalidElement);
+        }
+
+        Self::new(old_t, self.prime.clone())
+    }
+}
+
+/// 参照と所有値のすべての組み合わせに対して二項演算子を実装
+macro_rules! forward_binop {
+    ($trait:ident, $method:ident) => {
+        impl $trait<FieldElement> for FieldElement {
+            type Output = FieldElement;
+
+            fn $method(self, other: FieldElement) -> FieldElement {
+                (&self).$method(&other)
+            }
+        }
+
+        impl $trait<&FieldElement> for FieldElement {
+            type Output = FieldElement;
+
+            fn $method(self, other: &FieldElement) -> FieldElement {
+                (&self).$method(other)
+            }
+        }
+
+        impl $trait<FieldElement> for &FieldElement {
+            type Output = FieldElement;
+
+            fn $method(self, other: FieldElement) -> FieldElement {
+                self.$method(&other)
+            }
+        }
+    };
+}
+
+/// FieldElementに対する算術演算を実装
+impl Add<&FieldElement> for &FieldElement {
+    type Output = FieldElement;
+
+    fn add(self, other: &FieldElement) -> FieldElement {
+        self.checked_add(other)
+            .expect("Cannot add elements of different fields")
+    }
+}
+
+/// FieldElementに対する算術演算を実装（減算）
+impl Sub<&FieldElement> for &FieldElement {
+    type Output = FieldElement;
+
+    fn sub(self, other: &FieldElement) -> FieldElement {
+        self.checked_sub(other)
+            .expect("Cannot subtract elements of different fields")
+    }
+}
+
+/// FieldElementに対する算術演算を実装（乗算）
+impl Mul<&FieldElement> for &FieldElement {
+    type Output = FieldElement;
+
+    fn mul(self, other: &FieldElement) -> FieldElement {
+        self.checked_mul(other)
+            .expect("Cannot multiply elements of different fields")
+    }
+}
+
+/// FieldElementに対する算術演算を実装（除算）
+impl Div<&FieldElement> for &FieldElement {
+    type Output = FieldElement;
+
+    fn div(self, other: &FieldElement) -> FieldElement {
+        self.checked_div(other)
+            .expect("Cannot divide by zero or by an element of a different field")
+    }
+}
+
+forward_binop!(Add, add);
+forward_binop!(Sub, sub);
+forward_binop!(Mul, mul);
+forward_binop!(Div, div);
+
+/// FieldElementに対する算術演算を実装（単項マイナス）
+impl Neg for &FieldElement {
+    type Output = FieldElement;
+
+    fn neg(self) -> FieldElement {
+        let value = if self.value.is_zero() {
+            BigUint::zero()
+        } else {
+            &self.prime - &self.value
+        };
+        FieldElement::from_reduced(value, &self.prime)
+    }
+}
+
+impl Neg for FieldElement {
+    type Output = FieldElement;
+
+    fn neg(self) -> FieldElement {
+        -&self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_field_element_creation() {
+        let element = FieldElement::new(7, 13).unwrap();
+        assert_eq!(element.value(), &BigUint::from(7u32));
+        assert_eq!(element.prime(), &BigUint::from(13u32));
+    }
+
+    #[test]
+    fn test_field_element_addition() {
+        let a = FieldElement::new(7, 13).unwrap();
+        let b = FieldElement::new(12, 13).unwrap();
+        let result = a + b;
+        assert_eq!(result.value(), &BigUint::from(6u32)); // (7 + 12) % 13 = 6
+    }
+
+    #[test]
+    fn test_field_element_subtraction() {
+        let a = FieldElement::new(7, 13).unwrap();
+        let b = FieldElement::new(12, 13).unwrap();
+        let result = a - b;
+        assert_eq!(result.value(), &BigUint::from(8u32)); // (7 - 12 + 13) % 13 = 8
+    }
+
+    #[test]
+    fn test_field_element_multiplication() {
+        let a = FieldElement::new(3, 13).unwrap();
+        let b = FieldElement::new(12, 13).unwrap();
+        let result = a * b;
+        assert_eq!(result.value(), &BigUint::from(10u32)); // (3 * 12) % 13 = 10
+    }
+
+    #[test]
+    fn test_field_element_division() {
+        let a = FieldElement::new(3, 13).unwrap();
+        let b = FieldElement::new(2, 13).unwrap();
+        let result = a / b;
+        assert_eq!(result.value(), &BigUint::from(8u32)); // 3 * 7 % 13 = 8 (where 7 is the multiplicative inverse of 2 mod 13)
+    }
+
+    #[test]
+    fn test_field_element_power() {
+        let base = FieldElement::new(3, 13).unwrap();
+        let result = base.pow(3).unwrap();
+        assert_eq!(result.value(), &BigUint::from(1u32)); // 3^3 % 13 = 1
+    }
+
+    #[test]
+    fn test_field_element_large_prime() {
+        // secp256k1 の素数 p = 2²⁵⁶ - 2³² - 977
+        let prime: BigInt = (BigInt::one() << 256) - (BigInt::one() << 32) - 977;
+        let a = FieldElement::new(prime.clone() - 2, prime.clone()).unwrap();
+        let b = FieldElement::new(prime.clone() - 3, prime.clone()).unwrap();
+
+        // (-2) * (-3) = 6
+        assert_eq!((&a * &b).value(), &BigUint::from(6u32));
+        // (-2) + (-3) = -5
+        assert_eq!(&a + &b, FieldElement::new(-5, prime.clone()).unwrap());
+        // a * a⁻¹ = 1
+        assert_eq!((&a / &a).value(), &BigUint::one());
+        // フェルマーの小定理: a^(p-1) = 1
+        assert!(a.pow(prime.clone() - 1).unwrap().value().is_one());
+        assert_eq!(
+            a.pow(-1).unwrap() * &a,
+            FieldElement::new(1, prime).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_inverse_modulo_composite() {
+        // 合成数の法ではフェルマーの小定理が使えないので、拡張ユークリッド互除法で求める
+        let modulus = ((BigUint::one() << 61u32) - 1u32) * ((BigUint::one() << 31u32) - 1u32);
+        let a = FieldElement::new(5, BigInt::from(modulus.clone())).unwrap();
+        assert!((&a * a.try_inv().unwrap()).value().is_one());
+
+        let b = FieldElement::new((BigUint::one() << 31u32) - 1u32, modulus).unwrap();
+        assert!(matches!(b.try_inv(), Err(FieldError::InvalidElement)));
+    }
+
+    #[test]
+    fn test_checked_operations() {
+        let a = FieldElement::new(3, 13).unwrap();
+        let b = FieldElement::new(2, 13).unwrap();
+        assert_eq!(a.checked_add(&b).unwrap(), &a + &b);
+        assert_eq!(a.checked_sub(&b).unwrap(), &a - &b);
+        assert_eq!(a.checked_mul(&b).unwrap(), &a * &b);
+        assert_eq!(a.checked_div(&b).unwrap(), &a / &b);
+        assert_eq!(b.try_inv().unwrap().value(), &BigUint::from(7u32));
+    }
+
+    #[test]
+    fn test_checked_operations_errors() {
+        let a = FieldElement::new(3, 13).unwrap();
+        let zero = FieldElement::new(0, 13).unwrap();
+        let other_field = FieldElement::new(3, 17).unwrap();
+
+        assert!(matches!(
+            a.checked_add(&other_field),
+            Err(FieldError::MismatchedFields)
+        ));
+        assert!(matches!(
+            a.checked_sub(&other_field),
+            Err(FieldError::MismatchedFields)
+        ));
+        assert!(matches!(
+            a.checked_mul(&other_field),
+            Err(FieldError::MismatchedFields)
+        ));
+        assert!(matches!(
+            a.checked_div(&other_field),
+            Err(FieldError::MismatchedFields)
+        ));
+        assert!(matches!(
+            a.checked_div(&zero),
+            Err(FieldError::DivisionByZero)
+        ));
+        assert!(matches!(zero.try_inv(), Err(FieldError::DivisionByZero)));
+    }
+}
@@ -0,0 +1,563 @@
+use num_bigint::BigUint;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use super::{FieldElement, FieldError};
+
+/// 256ビット素数用のMontgomery体 (4 limbs)
+pub type MontgomeryField256 = MontgomeryField<4>;
+/// 384ビット素数用のMontgomery体 (6 limbs)
+pub type MontgomeryField384 = MontgomeryField<6>;
+/// 521ビット素数用のMontgomery体 (9 limbs)
+pub type MontgomeryField521 = MontgomeryField<9>;
+
+/// `[u64; N]` のlimb表現による素体のパラメータ
+///
+/// R = 2^(64N) として、元 a を aR mod p の形で保持する。
+/// 一度構築すれば、以降の演算はすべてヒープ確保なしで行われる。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MontgomeryField<const N: usize> {
+    modulus: [u64; N],
+    /// R mod p (Montgomery形式の1)
+    r: [u64; N],
+    /// R² mod p (Montgomery形式への変換用)
+    r2: [u64; N],
+    /// -p⁻¹ mod 2⁶⁴
+    inv: u64,
+}
+
+/// Montgomery形式で表現された体の元
+///
+/// 同じ体で演算を繰り返す場合の高速な表現。`FieldElement` との変換は
+/// `from_field_element` と `to_field_element` で計算の入口と出口に限って行う。
+#[derive(Debug, Clone, Copy)]
+pub struct MontgomeryElement<'a, const N: usize> {
+    limbs: [u64; N],
+    field: &'a MontgomeryField<N>,
+}
+
+/// a + b * c + carry を計算し、(下位64ビット, 上位64ビット) を返す
+#[inline(always)]
+fn mac(a: u64, b: u64, c: u64, carry: u64) -> (u64, u64) {
+    let t = (a as u128) + (b as u128) * (c as u128) + (carry as u128);
+    (t as u64, (t >> 64) as u64)
+}
+
+/// a + b + carry を計算
+#[inline(always)]
+fn adc(a: u64, b: u64, carry: u64) -> (u64, u64) {
+    let t = (a as u128) + (b as u128) + (carry as u128);
+    (t as u64, (t >> 64) as u64)
+}
+
+/// a - b - borrow を計算
+#[inline(always)]
+fn sbb(a: u64, b: u64, borrow: u64) -> (u64, u64) {
+    let t = (a as u128).wrapping_sub((b as u128) + (borrow as u128));
+    (t as u64, (t >> 127) as u64)
+}
+
+/// a - b を計算し、借りが発生したかを返す
+fn sub_limbs<const N: usize>(a: &[u64; N], b: &[u64; N]) -> ([u64; N], bool) {
+    let mut result = [0u64; N];
+    let mut borrow = 0;
+    for i in 0..N {
+        (result[i], borrow) = sbb(a[i], b[i], borrow);
+    }
+    (result, borrow != 0)
+}
+
+/// a + b を計算し、桁あふれが発生したかを返す
+fn add_limbs<const N: usize>(a: &[u64; N], b: &[u64; N]) -> ([u64; N], bool) {
+    let mut result = [0u64; N];
+    let mut carry = 0;
+    for i in 0..N {
+        (result[i], carry) = adc(a[i], b[i], carry);
+    }
+    (result, carry != 0)
+}
+
+fn limbs_to_biguint<const N: usize>(limbs: &[u64; N]) -> BigUint {
+    let digits: Vec<u32> = limbs
+        .iter()
+        .flat_map(|&limb| [limb as u32, (limb >> 32) as u32])
+        .collect();
+    BigUint::new(digits)
+}
+
+fn biguint_to_limbs<const N: usize>(value: &BigUint) -> Option<[u64; N]> {
+    let mut limbs = [0u64; N];
+    for (i, digit) in value.iter_u64_digits().enumerate() {
+        *limbs.get_mut(i)? = digit;
+    }
+    Some(limbs)
+}
+
+/// Montgomery体の生成と操作を定義
+impl<const N: usize> MontgomeryField<N> {
+    /// 奇素数 p から体のパラメータを構築
+    pub fn new(prime: &BigUint) -> Result<Self, FieldError> {
+        if N == 0 || !prime.bit(0) || *prime == BigUint::from(1u32) {
+            return Err(FieldError::InvalidElement);
+        }
+        let modulus = biguint_to_limbs::<N>(prime).ok_or(FieldError::InvalidElement)?;
+
+        // ニュートン法で p⁻¹ mod 2⁶⁴ を求める
+        let mut inv: u64 = 1;
+        for _ in 0..6 {
+            inv = inv.wrapping_mul(2u64.wrapping_sub(modulus[0].wrapping_mul(inv)));
+        }
+
+        let r = BigUint::from(1u32) << (64 * N);
+        let r_mod = biguint_to_limbs::<N>(&(&r % prime)).expect("R mod p fits in N limbs");
+        let r2 = biguint_to_limbs::<N>(&((&r * &r) % prime)).expect("R² mod p fits in N limbs");
+
+        Ok(Self {
+            modulus,
+            r: r_mod,
+            r2,
+            inv: inv.wrapping_neg(),
+        })
+    }
+
+    /// 体の素数を取得
+    pub fn prime(&self) -> BigUint {
+        limbs_to_biguint(&self.modulus)
+    }
+
+    /// 加法単位元
+    pub fn zero(&self) -> MontgomeryElement<'_, N> {
+        MontgomeryElement {
+            limbs: [0u64; N],
+            field: self,
+        }
+    }
+
+    /// 乗法単位元
+    pub fn one(&self) -> MontgomeryElement<'_, N> {
+        MontgomeryElement {
+            limbs: self.r,
+            field: self,
+        }
+    }
+
+    /// 整数値をMontgomery形式に変換
+    ///
+    /// 値を N limbs ごとに区切り、上位からHorner法で vR mod p を求める。
+    /// x < R, y < p なら mont_mul(x, y) < 2p なので、区切った値は p 以上でもよい。
+    pub fn element(&self, value: &BigUint) -> MontgomeryElement<'_, N> {
+        let mut digits = value.iter_u64_digits();
+        let mut remaining = digits.len();
+        let mut acc = [0u64; N];
+        while remaining > 0 {
+            // 最上位の区切りだけ N limbs に満たないことがある
+            let len = (remaining - 1) % N + 1;
+            let mut limbs = [0u64; N];
+            for limb in limbs[..len].iter_mut().rev() {
+                *limb = digits.next_back().expect("digit count is exact");
+            }
+            remaining -= len;
+            // acc·R + chunk·R
+            acc = self.add_mod(
+                &self.mont_mul(&acc, &self.r2),
+                &self.mont_mul(&limbs, &self.r2),
+            );
+        }
+        MontgomeryElement {
+            limbs: acc,
+            field: self,
+        }
+    }
+
+    /// FieldElementをMontgomery形式に変換
+    pub fn from_field_element(
+        &self,
+        element: &FieldElement,
+    ) -> Result<MontgomeryElement<'_, N>, FieldError> {
+        if biguint_to_limbs::<N>(element.prime()) != Some(self.modulus) {
+            return Err(FieldError::MismatchedFields);
+        }
+        Ok(self.element(element.value()))
+    }
+
+    /// a + b mod p (a, b < p)
+    fn add_mod(&self, a: &[u64; N], b: &[u64; N]) -> [u64; N] {
+        let (sum, carry) = add_limbs(a, b);
+        let (reduced, borrow) = sub_limbs(&sum, &self.modulus);
+        if carry || !borrow {
+            reduced
+        } else {
+            sum
+        }
+    }
+
+    /// a - b mod p (a, b < p)
+    fn sub_mod(&self, a: &[u64; N], b: &[u64; N]) -> [u64; N] {
+        let (difference, borrow) = sub_limbs(a, b);
+        if borrow {
+            add_limbs(&difference, &self.modulus).0
+        } else {
+            difference
+        }
+    }
+
+    /// Montgomery乗算 (CIOS法): a * b * R⁻¹ mod p
+    fn mont_mul(&self, a: &[u64; N], b: &[u64; N]) -> [u64; N] {
+        let p = &self.modulus;
+        let mut t = [0u64; N];
+        let mut t_hi = 0u64;
+
+        for &b_i in b.iter() {
+            // t += a * b[i]
+            let mut carry = 0;
+            for j in 0..N {
+                (t[j], carry) = mac(t[j], a[j], b_i, carry);
+            }
+            let (sum, overflow) = adc(t_hi, carry, 0);
+            t_hi = sum;
+
+            // t = (t + m * p) / 2⁶⁴
+            let m = t[0].wrapping_mul(self.inv);
+            let (_, mut carry) = mac(t[0], m, p[0], 0);
+            for j in 1..N {
+                (t[j - 1], carry) = mac(t[j], m, p[j], carry);
+            }
+            let (sum, c) = adc(t_hi, carry, 0);
+            t[N - 1] = sum;
+            t_hi = overflow + c;
+        }
+
+        // 最終減算で [0, p) に正規化
+        let (reduced, borrow) = sub_limbs(&t, p);
+        if t_hi != 0 || !borrow {
+            reduced
+        } else {
+            t
+        }
+    }
+}
+
+/// MontgomeryElementの生成と操作を定義
+impl<'a, const N: usize> MontgomeryElement<'a, N> {
+    /// 所属するMontgomery体を取得
+    pub fn field(&self) -> &'a MontgomeryField<N> {
+        self.field
+    }
+
+    /// 値が0かどうかを判定
+    pub fn is_zero(&self) -> bool {
+        self.limbs.iter().all(|&limb| limb == 0)
+    }
+
+    /// 通常の表現に戻した値を取得
+    pub fn value(&self) -> BigUint {
+        let mut one = [0u64; N];
+        one[0] = 1;
+        limbs_to_biguint(&self.field.mont_mul(&self.limbs, &one))
+    }
+
+    /// FieldElementに変換
+    pub fn to_field_element(&self) -> FieldElement {
+        FieldElement::new(self.value(), self.field.prime())
+            .expect("Montgomery field modulus is a valid prime")
+    }
+
+    /// 2乗を計算
+    pub fn square(&self) -> Self {
+        Self {
+            limbs: self.field.mont_mul(&self.limbs, &self.limbs),
+            field: self.field,
+        }
+    }
+
+    /// limb列で与えられた指数によるべき乗を計算
+    ///
+    /// 指数のビットに関わらず同じ乗算列を実行する。
+    pub fn pow_limbs(&self, exp: &[u64]) -> Self {
+        self.pow_digits(exp.iter().copied())
+    }
+
+    /// 多倍長整数の指数によるべき乗を計算
+    pub fn pow(&self, exp: &BigUint) -> Self {
+        self.pow_digits(exp.iter_u64_digits())
+    }
+
+    /// 下位から並んだ64ビットの桁による左から右へのべき乗
+    fn pow_digits(&self, exp: impl DoubleEndedIterator<Item = u64>) -> Self {
+        let mut result = self.field.one();
+        for limb in exp.rev() {
+            for bit in (0..64).rev() {
+                result = result.square();
+                let product = result * *self;
+                let choice = ((limb >> bit) & 1).wrapping_neg();
+                for i in 0..N {
+                    result.limbs[i] ^= choice & (result.limbs[i] ^ product.limbs[i]);
+                }
+            }
+        }
+        result
+    }
+
+    /// フェルマーの小定理 a^(p-2) により逆元を計算
+    pub fn inv(&self) -> Result<Self, FieldError> {
+        if self.is_zero() {
+            return Err(FieldError::DivisionByZero);
+        }
+        let mut two = [0u64; N];
+        two[0] = 2;
+        let (exp, _) = sub_limbs(&self.field.modulus, &two);
+        Ok(self.pow_limbs(&exp))
+    }
+
+    fn assert_same_field(&self, other: &Self, operation: &str) {
+        assert!(
+            std::ptr::eq(self.field, other.field) || self.field.modulus == other.field.modulus,
+            "Cannot {} elements of different fields",
+            operation
+        );
+    }
+}
+
+impl<const N: usize> PartialEq for MontgomeryElement<'_, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.field.modulus == other.field.modulus && self.limbs == other.limbs
+    }
+}
+
+impl<const N: usize> Eq for MontgomeryElement<'_, N> {}
+
+impl<const N: usize> From<MontgomeryElement<'_, N>> for FieldElement {
+    fn from(element: MontgomeryElement<'_, N>) -> Self {
+        element.to_field_element()
+    }
+}
+
+/// MontgomeryElementに対する算術演算を実装
+impl<const N: usize> Add for MontgomeryElement<'_, N> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        self.assert_same_field(&other, "add");
+        Self {
+            limbs: self.field.add_mod(&self.limbs, &other.limbs),
+            field: self.field,
+        }
+    }
+}
+
+/// MontgomeryElementに対する算術演算を実装（減算）
+impl<const N: usize> Sub for MontgomeryElement<'_, N> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.assert_same_field(&other, "subtract");
+        Self {
+            limbs: self.field.sub_mod(&self.limbs, &other.limbs),
+            field: self.field,
+        }
+    }
+}
+
+/// MontgomeryElementに対する算術演算を実装（乗算）
+impl<const N: usize> Mul for MontgomeryElement<'_, N> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        self.assert_same_field(&other, "multiply");
+        Self {
+            limbs: self.field.mont_mul(&self.limbs, &other.limbs),
+            field: self.field,
+        }
+    }
+}
+
+/// MontgomeryElementに対する算術演算を実装（除算）
+impl<const N: usize> Div for MontgomeryElement<'_, N> {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        self.assert_same_field(&other, "divide");
+        let inverse = other.inv().expect("Division by zero");
+        Self {
+            limbs: self.field.mont_mul(&self.limbs, &inverse.limbs),
+            field: self.field,
+        }
+    }
+}
+
+/// MontgomeryElementに対する算術演算を実装（単項マイナス）
+impl<const N: usize> Neg for MontgomeryElement<'_, N> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        if self.is_zero() {
+            return self;
+        }
+        Self {
+            limbs: sub_limbs(&self.field.modulus, &self.limbs).0,
+            field: self.field,
+        }
+    }
+}
+
+/// p のlimb数に合うMontgomery体で a^e mod p を計算
+///
+/// 1 limbに収まる小さな素数、偶数、9 limbsを超える値では `None` を返す。
+/// 変換は入口と出口の1回ずつで、べき乗の途中はヒープ確保なしで計算する。
+pub(super) fn pow_mod(value: &BigUint, exp: &BigUint, prime: &BigUint) -> Option<BigUint> {
+    fn pow<const N: usize>(value: &BigUint, exp: &BigUint, prime: &BigUint) -> Option<BigUint> {
+        let field = MontgomeryField::<N>::new(prime).ok()?;
+        Some(field.element(value).pow(exp).value())
+    }
+    match prime.bits().div_ceil(64) {
+        2..=4 => pow::<4>(value, exp, prime),
+        5..=6 => pow::<6>(value, exp, prime),
+        7..=9 => pow::<9>(value, exp, prime),
+        _ => None,
+    }
+}
+
+/// p のlimb数に合うMontgomery体で a⁻¹ mod p を計算
+///
+/// フェルマーの小定理を使うので、結果が逆元になっているかを確かめて返す。
+/// p が素数でない場合や対象外の p では `None` を返す。
+pub(super) fn inv_mod(value: &BigUint, prime: &BigUint) -> Option<BigUint> {
+    fn inv<const N: usize>(value: &BigUint, prime: &BigUint) -> Option<BigUint> {
+        let field = MontgomeryField::<N>::new(prime).ok()?;
+        let element = field.element(value);
+        let inverse = element.inv().ok()?;
+        (element * inverse == field.one()).then(|| inverse.value())
+    }
+    match prime.bits().div_ceil(64) {
+        2..=4 => inv::<4>(value, prime),
+        5..=6 => inv::<6>(value, prime),
+        7..=9 => inv::<9>(value, prime),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use num_bigint::BigInt;
+    use num_traits::Zero;
+
+    fn p256() -> BigUint {
+        let one = BigInt::from(1);
+        let p: BigInt = (&one << 256) - (&one << 224) + (&one << 192) + (&one << 96) - 1;
+        p.magnitude().clone()
+    }
+
+    /// 決定的な疑似乱数列でテスト値を生成
+    fn sample_values(prime: &BigUint, count: usize) -> Vec<BigUint> {
+        let mut state = BigUint::from(0x1234_5678_9abc_def0u64);
+        (0..count)
+            .map(|_| {
+                state = (&state * &state + BigUint::from(0x9e37_79b9u32)) % prime;
+                state.clone()
+            })
+            .collect()
+    }
+
+    fn check_against_field_element<const N: usize>(prime: BigUint) {
+        let field = MontgomeryField::<N>::new(&prime).unwrap();
+        let values = sample_values(&prime, 8);
+
+        for pair in values.windows(2) {
+            let (a, b) = (&pair[0], &pair[1]);
+            let fa = FieldElement::new(a.clone(), prime.clone()).unwrap();
+            let fb = FieldElement::new(b.clone(), prime.clone()).unwrap();
+            let ma = field.element(a);
+            let mb = field.element(b);
+
+            assert_eq!(FieldElement::from(ma + mb), &fa + &fb);
+            assert_eq!(FieldElement::from(ma - mb), &fa - &fb);
+            assert_eq!(FieldElement::from(ma * mb), &fa * &fb);
+            assert_eq!(FieldElement::from(ma / mb), &fa / &fb);
+            assert_eq!(FieldElement::from(-ma), -&fa);
+            assert_eq!(ma.square(), ma * ma);
+            assert_eq!(ma * ma.inv().unwrap(), field.one());
+
+            // べき乗と逆元は多倍長整数での計算と一致する
+            let expected_pow = a.modpow(b, &prime);
+            assert_eq!(ma.pow(b).value(), expected_pow);
+            assert_eq!(fa.pow(b.clone()).unwrap().value(), &expected_pow);
+            let expected_inv = a.modpow(&(&prime - 2u32), &prime);
+            assert_eq!(ma.inv().unwrap().value(), expected_inv);
+            assert_eq!(fa.try_inv().unwrap().value(), &expected_inv);
+        }
+    }
+
+    #[test]
+    fn test_small_prime() {
+        let field = MontgomeryField::<1>::new(&BigUint::from(223u32)).unwrap();
+        let a = field.element(&BigUint::from(192u32));
+        let b = field.element(&BigUint::from(105u32));
+        assert_eq!((a * b).value(), BigUint::from(192u32 * 105 % 223));
+        assert_eq!((a + b).value(), BigUint::from((192u32 + 105) % 223));
+        assert_eq!((b - a).value(), BigUint::from(223u32 + 105 - 192));
+    }
+
+    #[test]
+    fn test_256_bit_prime() {
+        check_against_field_element::<4>(p256());
+    }
+
+    #[test]
+    fn test_384_bit_prime() {
+        let one = BigInt::from(1);
+        let p: BigInt = (&one << 384) - (&one << 128) - (&one << 96) + (&one << 32) - 1;
+        check_against_field_element::<6>(p.magnitude().clone());
+    }
+
+    #[test]
+    fn test_521_bit_prime() {
+        let p = (BigUint::from(1u32) << 521) - 1u32;
+        check_against_field_element::<9>(p);
+    }
+
+    #[test]
+    fn test_invalid_modulus() {
+        // 偶数やlimbに収まらない値は拒否する
+        assert!(MontgomeryField::<4>::new(&BigUint::from(224u32)).is_err());
+        assert!(MontgomeryField::<1>::new(&p256()).is_err());
+    }
+
+    #[test]
+    fn test_zero_inverse() {
+        let field = MontgomeryField256::new(&p256()).unwrap();
+        assert!(matches!(
+            field.zero().inv(),
+            Err(FieldError::DivisionByZero)
+        ));
+    }
+
+    #[test]
+    fn test_element_reduces_large_values() {
+        // p 以上の値や N limbs を超える値も正しく簡約する
+        let prime = p256();
+        let field = MontgomeryField256::new(&prime).unwrap();
+        for value in [
+            prime.clone(),
+            &prime + 5u32,
+            BigUint::from(u64::MAX),
+            (&prime << 300u32) + 7u32,
+        ] {
+            assert_eq!(field.element(&value).value(), &value % &prime);
+        }
+
+        let small = MontgomeryField::<1>::new(&BigUint::from(223u32)).unwrap();
+        let value = BigUint::from(u64::MAX) * 3u32;
+        assert_eq!(small.element(&value).value(), &value % 223u32);
+    }
+
+    #[test]
+    fn test_conversion_roundtrip() {
+        let prime = p256();
+        let field = MontgomeryField256::new(&prime).unwrap();
+        let element = FieldElement::new(prime.clone() - 1u32, prime).unwrap();
+        let montgomery = field.from_field_element(&element).unwrap();
+        assert_eq!(montgomery.to_field_element(), element);
+        assert!(!montgomery.is_zero());
+        assert!(Zero::is_zero(&(montgomery + field.one()).value()));
+    }
+}
@@ -0,0 +1,260 @@
+//! 素体 F_p 上の1変数多項式
+
+use num_bigint::{BigInt, BigUint};
+use num_integer::Integer;
+use num_traits::{One, Zero};
+
+/// 係数を低次から順に並べた多項式（最高次の係数は0でない）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Poly(Vec<BigUint>);
+
+impl Poly {
+    pub(crate) fn zero() -> Self {
+        Self(Vec::new())
+    }
+
+    pub(crate) fn is_zero(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// 次数（零多項式では `None`）
+    pub(crate) fn degree(&self) -> Option<usize> {
+        self.0.len().checked_sub(1)
+    }
+
+    /// 係数を取得
+    pub(crate) fn coeff(&self, i: usize) -> BigUint {
+        self.0.get(i).cloned().unwrap_or_default()
+    }
+
+    fn normalized(mut coeffs: Vec<BigUint>) -> Self {
+        while coeffs.last().is_some_and(Zero::is_zero) {
+            coeffs.pop();
+        }
+        Self(coeffs)
+    }
+}
+
+/// F_p[x] の演算を定義
+#[derive(Debug, Clone)]
+pub(crate) struct PolyRing {
+    p: BigUint,
+}
+
+impl PolyRing {
+    pub(crate) fn new(p: BigUint) -> Self {
+        Self { p }
+    }
+
+    /// 整数係数の列から多項式を生成
+    pub(crate) fn poly(&self, coeffs: &[BigInt]) -> Poly {
+        let p = BigInt::from(self.p.clone());
+        Poly::normalized(
+            coeffs
+                .iter()
+                .map(|c| c.mod_floor(&p).magnitude().clone())
+                .collect(),
+        )
+    }
+
+    /// 定数多項式
+    pub(crate) fn constant(&self, c: impl Into<BigInt>) -> Poly {
+        self.poly(&[c.into()])
+    }
+
+    /// 多項式 x
+    pub(crate) fn x(&self) -> Poly {
+        Poly::normalized(vec![BigUint::zero(), BigUint::one()])
+    }
+
+    pub(crate) fn add(&self, a: &Poly, b: &Poly) -> Poly {
+        let len = a.0.len().max(b.0.len());
+        Poly::normalized(
+            (0..len)
+                .map(|i| (a.coeff(i) + b.coeff(i)) % &self.p)
+                .collect(),
+        )
+    }
+
+    pub(crate) fn neg(&self, a: &Poly) -> Poly {
+        Poly::normalized(
+            a.0.iter()
+                .map(|c| if c.is_zero() { c.clone() } else { &self.p - c })
+                .collect(),
+        )
+    }
+
+    pub(crate) fn sub(&self, a: &Poly, b: &Poly) -> Poly {
+        self.add(a, &self.neg(b))
+    }
+
+    pub(crate) fn scale(&self, a: &Poly, c: &BigUint) -> Poly {
+        Poly::normalized(a.0.iter().map(|x| x * c % &self.p).collect())
+    }
+
+    pub(crate) fn mul(&self, a: &Poly, b: &Poly) -> Poly {
+        if a.is_zero() || b.is_zero() {
+            return Poly::zero();
+        }
+        // 係数ごとに積を足し合わせてから一度だけ剰余をとる
+        let mut coeffs = vec![BigUint::zero(); a.0.len() + b.0.len() - 1];
+        for (i, x) in a.0.iter().enumerate() {
+            if x.is_zero() {
+                continue;
+            }
+            for (j, y) in b.0.iter().enumerate() {
+                coeffs[i + j] += x * y;
+            }
+        }
+        Poly::normalized(coeffs.into_iter().map(|c| c % &self.p).collect())
+    }
+
+    /// 体の元の逆元
+    fn inv_scalar(&self, c: &BigUint) -> BigUint {
+        c.modpow(&(&self.p - 2u32), &self.p)
+    }
+
+    /// 最高次の係数を1にする
+    pub(crate) fn monic(&self, a: &Poly) -> Poly {
+        match a.0.last() {
+            Some(lead) => self.scale(a, &self.inv_scalar(lead)),
+            None => Poly::zero(),
+        }
+    }
+
+    /// 商と余りを計算（b は零多項式であってはならない）
+    pub(crate) fn divrem(&self, a: &Poly, b: &Poly) -> (Poly, Poly) {
+        let b_degree = b.degree().expect("division by zero polynomial");
+        let lead_inv = self.inv_scalar(&b.0[b_degree]);
+
+        let mut remainder = a.0.clone();
+        if remainder.len() <= b_degree {
+            return (Poly::zero(), a.clone());
+        }
+        let mut quotient = vec![BigUint::zero(); remainder.len() - b_degree];
+
+        for i in (0..quotient.len()).rev() {
+            let c = &remainder[i + b_degree] * &lead_inv % &self.p;
+            if c.is_zero() {
+                continue;
+            }
+            for (j, bj) in b.0.iter().enumerate() {
+                let t = &c * bj % &self.p;
+                let r = &mut remainder[i + j];
+                if *r >= t {
+                    *r -= t;
+                } else {
+                    *r += &self.p - t;
+                }
+            }
+            quotient[i] = c;
+        }
+
+        remainder.truncate(b_degree);
+        (Poly::normalized(quotient), Poly::normalized(remainder))
+    }
+
+    pub(crate) fn rem(&self, a: &Poly, b: &Poly) -> Poly {
+        if a.0.len() < b.0.len() {
+            return a.clone();
+        }
+        self.divrem(a, b).1
+    }
+
+    pub(crate) fn mul_mod(&self, a: &Poly, b: &Poly, m: &Poly) -> Poly {
+        self.rem(&self.mul(a, b), m)
+    }
+
+    /// 繰り返し2乗法で a^e mod m を計算
+    pub(crate) fn pow_mod(&self, a: &Poly, e: &BigUint, m: &Poly) -> Poly {
+        let mut result = self.rem(&self.constant(1), m);
+        let base = self.rem(a, m);
+        for bit in (0..e.bits()).rev() {
+            result = self.mul_mod(&result, &result, m);
+            if e.bit(bit) {
+                result = self.mul_mod(&result, &base, m);
+            }
+        }
+        result
+    }
+
+    /// モニックな最大公約多項式
+    pub(crate) fn gcd(&self, a: &Poly, b: &Poly) -> Poly {
+        let (mut r0, mut r1) = (a.clone(), b.clone());
+        while !r1.is_zero() {
+            let r = self.rem(&r0, &r1);
+            r0 = std::mem::replace(&mut r1, r);
+        }
+        self.monic(&r0)
+    }
+
+    /// a mod m の逆元を計算
+    ///
+    /// 逆元が存在しない場合は、非自明な公約因子 gcd(a, m) を `Err` で返す。
+    pub(crate) fn inv_mod(&self, a: &Poly, m: &Poly) -> Result<Poly, Poly> {
+        let (mut r0, mut r1) = (m.clone(), self.rem(a, m));
+        let (mut s0, mut s1) = (Poly::zero(), self.constant(1));
+        while !r1.is_zero() {
+            let (q, r) = self.divrem(&r0, &r1);
+            r0 = std::mem::replace(&mut r1, r);
+            let s = self.sub(&s0, &self.mul(&q, &s1));
+            s0 = std::mem::replace(&mut s1, s);
+        }
+
+        if r0.degree() == Some(0) {
+            let inv = self.inv_scalar(&r0.0[0]);
+            Ok(self.rem(&self.scale(&s0, &inv), m))
+        } else {
+            Err(self.monic(&r0))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn poly(ring: &PolyRing, coeffs: &[i64]) -> Poly {
+        let coeffs: Vec<BigInt> = coeffs.iter().map(|&c| BigInt::from(c)).collect();
+        ring.poly(&coeffs)
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let ring = PolyRing::new(BigUint::from(13u32));
+        let a = poly(&ring, &[1, 2, 3]);
+        let b = poly(&ring, &[12, 1]);
+
+        assert_eq!(ring.add(&a, &b), poly(&ring, &[0, 3, 3]));
+        assert_eq!(ring.sub(&a, &a), Poly::zero());
+        // (3x² + 2x + 1)(x - 1) = 3x³ - x² - x - 1
+        assert_eq!(ring.mul(&a, &b), poly(&ring, &[-1, -1, -1, 3]));
+
+        let (q, r) = ring.divrem(&a, &b);
+        assert_eq!(ring.add(&ring.mul(&q, &b), &r), a);
+        assert_eq!(r, ring.constant(6));
+    }
+
+    #[test]
+    fn test_gcd_and_inverse() {
+        let ring = PolyRing::new(BigUint::from(13u32));
+        // (x - 1)(x - 2) と (x - 1)(x - 3)
+        let a = ring.mul(&poly(&ring, &[-1, 1]), &poly(&ring, &[-2, 1]));
+        let b = ring.mul(&poly(&ring, &[-1, 1]), &poly(&ring, &[-3, 1]));
+        assert_eq!(ring.gcd(&a, &b), poly(&ring, &[-1, 1]));
+        assert_eq!(ring.inv_mod(&a, &b), Err(poly(&ring, &[-1, 1])));
+
+        let m = poly(&ring, &[2, 0, 0, 1]);
+        let inv = ring.inv_mod(&a, &m).unwrap();
+        assert_eq!(ring.mul_mod(&a, &inv, &m), ring.constant(1));
+    }
+
+    #[test]
+    fn test_pow_mod() {
+        // F_p 上で x^p ≡ x (mod x^p - x)
+        let ring = PolyRing::new(BigUint::from(7u32));
+        let m = poly(&ring, &[0, -1, 0, 0, 0, 0, 0, 1]);
+        let x = ring.x();
+        assert_eq!(ring.pow_mod(&x, &BigUint::from(7u32), &m), x);
+    }
+}
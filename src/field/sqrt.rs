@@ -0,0 +1,193 @@
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+
+use super::FieldElement;
+
+/// 平方剰余と平方根の計算を定義
+impl FieldElement {
+    /// ルジャンドル記号 (a/p) を計算
+    ///
+    /// 0なら0、0でない平方剰余なら1、平方非剰余なら-1を返す。
+    pub fn legendre(&self) -> i8 {
+        if self.value.is_zero() {
+            return 0;
+        }
+        if self.prime == BigUint::from(2u32) {
+            return 1;
+        }
+
+        // オイラーの規準: a^((p-1)/2) ≡ ±1 (mod p)
+        let exp = (&self.prime - 1u32) >> 1;
+        if self.value.modpow(&exp, &self.prime).is_one() {
+            1
+        } else {
+            -1
+        }
+    }
+
+    /// 平方剰余（または0）かどうかを判定
+    pub fn is_square(&self) -> bool {
+        self.legendre() >= 0
+    }
+
+    /// 平方根を計算
+    ///
+    /// 平方根が存在する場合は `(r, -r)` の組を返す。
+    /// p ≡ 3 (mod 4) では a^((p+1)/4)、p ≡ 5 (mod 8) ではAtkinの方法、
+    /// それ以外ではTonelli–Shanksのアルゴリズムを用いる。
+    pub fn sqrt(&self) -> Option<(Self, Self)> {
+        if self.value.is_zero() || self.prime == BigUint::from(2u32) {
+            return Some((self.clone(), self.clone()));
+        }
+        if self.legendre() != 1 {
+            return None;
+        }
+
+        let p_mod_8 = (&self.prime % 8u32).to_u32_digits().first().copied();
+        let root = match p_mod_8 {
+            Some(3) | Some(7) => self.sqrt_3_mod_4(),
+            Some(5) => self.sqrt_5_mod_8(),
+            _ => self.sqrt_tonelli_shanks(),
+        };
+
+        debug_assert_eq!(&root * &root, *self);
+        let negated = -&root;
+        Some((root, negated))
+    }
+
+    /// p ≡ 3 (mod 4) の場合: r = a^((p+1)/4)
+    fn sqrt_3_mod_4(&self) -> Self {
+        let exp = (&self.prime + 1u32) >> 2;
+        Self::from_reduced(self.value.modpow(&exp, &self.prime), &self.prime)
+    }
+
+    /// p ≡ 5 (mod 8) の場合のAtkinの方法
+    fn sqrt_5_mod_8(&self) -> Self {
+        // b = (2a)^((p-5)/8), i = 2ab², r = ab(i - 1)
+        let two_a = self + self;
+        let exp = (&self.prime - 5u32) >> 3;
+        let b = Self::from_reduced(two_a.value.modpow(&exp, &self.prime), &self.prime);
+        let i = &two_a * &b * &b;
+        let one = Self::from_reduced(BigUint::one(), &self.prime);
+        self * &b * (i - one)
+    }
+
+    /// 一般の奇素数に対するTonelli–Shanksのアルゴリズム
+    fn sqrt_tonelli_shanks(&self) -> Self {
+        let p = &self.prime;
+
+        // p - 1 = q·2^s (q は奇数)
+        let p_minus_one = p - 1u32;
+        let s = p_minus_one
+            .trailing_zeros()
+            .expect("p - 1 is nonzero for an odd prime");
+        let q = &p_minus_one >> s;
+
+        // 平方非剰余 z を小さい順に探す
+        let mut z = Self::from_reduced(BigUint::from(2u32), p);
+        while z.legendre() != -1 {
+            z = Self::from_reduced(&z.value + 1u32, p);
+        }
+
+        let mut m = s;
+        let mut c = Self::from_reduced(z.value.modpow(&q, p), p);
+        let mut t = Self::from_reduced(self.value.modpow(&q, p), p);
+        let mut r = Self::from_reduced(self.value.modpow(&((&q + 1u32) >> 1), p), p);
+
+        while !t.value.is_one() {
+            // t^(2^i) = 1 となる最小の i を探す
+            let mut i = 0;
+            let mut t_power = t.clone();
+            while !t_power.value.is_one() {
+                t_power = &t_power * &t_power;
+                i += 1;
+            }
+
+            let mut b = c.clone();
+            for _ in 0..(m - i - 1) {
+                b = &b * &b;
+            }
+
+            m = i;
+            c = &b * &b;
+            t = t * &c;
+            r = r * b;
+        }
+
+        r
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 全要素について平方根の結果を総当たりで確認
+    fn check_all_elements(prime: u32) {
+        let squares: Vec<u32> = (0..prime).map(|x| x * x % prime).collect();
+
+        for value in 0..prime {
+            let element = FieldElement::new(value, prime).unwrap();
+            let is_square = squares.contains(&value);
+            assert_eq!(element.is_square(), is_square, "p = {prime}, a = {value}");
+
+            match element.sqrt() {
+                Some((r1, r2)) => {
+                    assert!(is_square);
+                    assert_eq!(&r1 * &r1, element);
+                    assert_eq!(&r2 * &r2, element);
+                    assert_eq!(r1, -r2);
+                }
+                None => assert!(!is_square),
+            }
+        }
+    }
+
+    #[test]
+    fn test_legendre() {
+        // F13 の平方剰余は {1, 3, 4, 9, 10, 12}
+        let legendre = |v| FieldElement::new(v, 13).unwrap().legendre();
+        assert_eq!(legendre(0), 0);
+        assert_eq!(legendre(4), 1);
+        assert_eq!(legendre(10), 1);
+        assert_eq!(legendre(2), -1);
+        assert_eq!(legendre(5), -1);
+    }
+
+    #[test]
+    fn test_sqrt_p_3_mod_4() {
+        check_all_elements(223);
+        check_all_elements(7);
+    }
+
+    #[test]
+    fn test_sqrt_p_5_mod_8() {
+        check_all_elements(13);
+        check_all_elements(109);
+    }
+
+    #[test]
+    fn test_sqrt_tonelli_shanks() {
+        // p ≡ 1 (mod 8)
+        check_all_elements(17);
+        check_all_elements(257);
+        check_all_elements(337);
+    }
+
+    #[test]
+    fn test_sqrt_large_prime() {
+        // P-224 の素数 p = 2²²⁴ - 2⁹⁶ + 1 は p - 1 が 2⁹⁶ で割り切れる
+        let one = BigUint::one();
+        let prime = (&one << 224) - (&one << 96) + &one;
+        let x = FieldElement::new(BigUint::from(0xdead_beef_u64), prime).unwrap();
+        let square = &x * &x;
+        let (r1, r2) = square.sqrt().unwrap();
+        assert!(r1 == x || r2 == x);
+    }
+
+    #[test]
+    fn test_sqrt_char_two() {
+        let one = FieldElement::new(1, 2).unwrap();
+        assert_eq!(one.sqrt(), Some((one.clone(), one)));
+    }
+}
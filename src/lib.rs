@@ -27,12 +27,14 @@
 /// ```
 use pyo3::prelude::*;
 
+mod arith;
 pub mod curve;
 pub mod field;
 pub mod point;
 pub mod protocols;
 
 use curve::Curve;
+use num_bigint::BigInt;
 use point::Point;
 
 #[pyclass]
@@ -50,14 +52,14 @@ struct PyPoint {
 #[pymethods]
 impl PyCurve {
     #[new]
-    fn new(a: i64, b: i64, prime: i64) -> PyResult<Self> {
+    fn new(a: BigInt, b: BigInt, prime: BigInt) -> PyResult<Self> {
         Ok(PyCurve {
             inner: Curve::new(a, b, prime)
                 .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?,
         })
     }
 
-    fn point(&self, x: i64, y: i64, py: Python<'_>) -> PyResult<Py<PyPoint>> {
+    fn point(&self, x: BigInt, y: BigInt, py: Python<'_>) -> PyResult<Py<PyPoint>> {
         let point = PyPoint {
             inner: self
                 .inner
@@ -96,7 +98,7 @@ impl PyPoint {
     }
 
     fn __repr__(&self) -> String {
-        match (self.inner.x, self.inner.y) {
+        match (&self.inner.x, &self.inner.y) {
             (Some(x), Some(y)) => format!("Point({}, {})", x.value(), y.value()),
             _ => "Point(infinity)".to_string(),
         }
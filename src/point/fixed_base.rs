@@ -0,0 +1,165 @@
+use num_bigint::{BigInt, BigUint};
+use num_integer::Integer;
+use num_traits::Zero;
+
+use super::{conditional_swap, JacobianPoint, Point, PointError};
+
+/// 固定基点のスカラー倍算用の事前計算テーブル
+///
+/// 幅 w のウィンドウごとに j·2^(wi)·G (0 ≤ j < 2^w) をアフィン座標で保持する。
+/// k·G はウィンドウごとの表引きと混合加算だけで求まり、2倍算は不要になる。
+/// 同じ生成点で何度も鍵生成や暗号化を行う場合に、一度だけ構築して使い回す。
+#[derive(Debug, Clone)]
+pub struct FixedBaseTable {
+    base: Point,
+    order: BigUint,
+    window: usize,
+    table: Vec<Vec<Point>>,
+}
+
+/// FixedBaseTableの生成と操作を定義
+impl FixedBaseTable {
+    /// 既定のウィンドウ幅 (4) でテーブルを構築
+    pub fn new(base: &Point, order: &BigUint) -> Result<Self, PointError> {
+        Self::with_window(base, order, 4)
+    }
+
+    /// 指定したウィンドウ幅でテーブルを構築
+    ///
+    /// スカラーは基点の位数 n で簡約するので、n 未満のスカラーを覆うだけのウィンドウを用意する。
+    /// n·G = O でなければ `InvalidOrder` を返す。
+    pub fn with_window(base: &Point, order: &BigUint, window: usize) -> Result<Self, PointError> {
+        if !(1..=8).contains(&window) {
+            return Err(PointError::InvalidWindowSize);
+        }
+        if order.is_zero() || !(base.clone() * order)?.is_infinity() {
+            return Err(PointError::InvalidOrder);
+        }
+
+        let bits = order.bits() as usize;
+        let windows = bits.div_ceil(window);
+        let mut multiples = Vec::with_capacity(windows << window);
+
+        // window_base = 2^(wi)·G
+        let mut window_base = JacobianPoint::from(base);
+        for _ in 0..windows {
+            let mut multiple = JacobianPoint::infinity(base.a.clone(), base.b.clone());
+            for _ in 0..(1 << window) {
+                multiples.push(multiple.clone());
+                multiple = (&multiple + &window_base)?;
+            }
+
+            for _ in 0..window {
+                window_base = window_base.double();
+            }
+        }
+
+        // 全要素を1回の逆元計算でアフィン座標に揃える
+        let table = JacobianPoint::batch_to_affine(&multiples)?
+            .chunks(1 << window)
+            .map(<[Point]>::to_vec)
+            .collect();
+
+        Ok(Self {
+            base: base.clone(),
+            order: order.clone(),
+            window,
+            table,
+        })
+    }
+
+    /// テーブルの基点を取得
+    pub fn base(&self) -> &Point {
+        &self.base
+    }
+
+    /// 基点の位数を取得
+    pub fn order(&self) -> &BigUint {
+        &self.order
+    }
+
+    /// テーブルを用いて k·G を計算
+    ///
+    /// スカラーを位数 n で [0, n) に簡約した上で、ウィンドウごとに行の全要素を走査して
+    /// 条件付き交換で1つを選び、例外ケースで分岐しない混合加算を行う。
+    /// スカラーの大きさや符号によって演算列は変わらない。
+    pub fn mul(&self, scalar: &BigInt) -> Result<Point, PointError> {
+        let k = scalar
+            .mod_floor(&BigInt::from(self.order.clone()))
+            .into_parts()
+            .1;
+
+        let mut result = JacobianPoint::infinity(self.base.a.clone(), self.base.b.clone());
+        for (i, row) in self.table.iter().enumerate() {
+            let mut digit = 0;
+            for bit in 0..self.window {
+                digit |= (k.bit((i * self.window + bit) as u64) as usize) << bit;
+            }
+            // 秘密の添字で表引きせず、行の全要素から選ぶ
+            let mut selected = row[0].clone();
+            for (j, entry) in row.iter().enumerate().skip(1) {
+                conditional_swap(&mut selected, &mut entry.clone(), j == digit);
+            }
+            result = result.add_mixed_regular(&selected)?;
+        }
+
+        result.to_affine()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curve::Curve;
+
+    #[test]
+    fn test_fixed_base_matches_ladder() {
+        let curve = Curve::new(0, 7, 223).unwrap();
+        let g = curve.point(15, 86).unwrap();
+        let order = BigUint::from(7u32);
+
+        for window in 1..=5 {
+            let table = FixedBaseTable::with_window(&g, &order, window).unwrap();
+            assert_eq!(table.base(), &g);
+            assert_eq!(table.order(), &order);
+            for k in -30..30 {
+                assert_eq!(
+                    table.mul(&BigInt::from(k)).unwrap(),
+                    (g.clone() * k).unwrap(),
+                    "window = {window}, k = {k}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_fixed_base_reduces_large_scalar() {
+        let params = Curve::secp256k1();
+        let table = FixedBaseTable::new(&params.generator, &params.order).unwrap();
+
+        // 位数を超えるスカラーや負のスカラーは n で簡約してから計算する
+        let k = BigInt::from(params.order.clone()) * 5 + 3;
+        assert_eq!(
+            table.mul(&k).unwrap(),
+            (params.generator.clone() * 3).unwrap()
+        );
+        assert_eq!(
+            table.mul(&BigInt::from(-2)).unwrap(),
+            -(params.generator.clone() * 2).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_rejects_wrong_order() {
+        let curve = Curve::new(0, 7, 223).unwrap();
+        let g = curve.point(15, 86).unwrap();
+        for order in [0u32, 6, 8] {
+            assert!(matches!(
+                FixedBaseTable::new(&g, &BigUint::from(order)),
+                Err(PointError::InvalidOrder)
+            ));
+        }
+        // 位数の倍数は受け付ける
+        assert!(FixedBaseTable::new(&g, &BigUint::from(14u32)).is_ok());
+    }
+}
@@ -0,0 +1,127 @@
+use num_bigint::{BigInt, BigUint};
+use num_traits::Signed;
+use std::fmt::Debug;
+
+use super::{Point, PointError};
+
+/// 曲線の形によらない点の群演算
+///
+/// 短縮Weierstrass形式やツイストEdwards形式の点を同じように扱い、
+/// 署名や鍵交換などのプロトコルを曲線に依存せずに書くためのトレイト。
+pub trait CurvePoint: Clone + PartialEq + Debug {
+    /// 同じ曲線の単位元
+    fn identity(&self) -> Self;
+
+    /// 単位元かどうかを判定
+    fn is_identity(&self) -> bool;
+
+    /// 同じ曲線上の点かどうかを判定
+    fn is_on_same_curve(&self, other: &Self) -> bool;
+
+    /// 点の加算
+    fn add_point(&self, other: &Self) -> Result<Self, PointError>;
+
+    /// 逆元
+    fn negate(&self) -> Self;
+
+    /// 秘密スカラーによる倍算
+    fn mul_secret(&self, scalar: &BigUint) -> Result<Self, PointError>;
+
+    /// 公開スカラーによる可変時間の倍算
+    fn mul_public(&self, scalar: &BigUint) -> Result<Self, PointError>;
+
+    /// 点のバイト列表現 (ハッシュの入力などに使う)
+    fn to_bytes(&self) -> Vec<u8>;
+
+    /// Σ kᵢ·Pᵢ を計算 (公開スカラー用)
+    fn linear_combination(terms: &[(Self, BigInt)]) -> Result<Self, PointError> {
+        let (first, _) = terms.first().ok_or(PointError::EmptyInput)?;
+        terms
+            .iter()
+            .try_fold(first.identity(), |acc, (point, scalar)| {
+                let term = point.mul_public(scalar.magnitude())?;
+                let term = if scalar.is_negative() {
+                    term.negate()
+                } else {
+                    term
+                };
+                acc.add_point(&term)
+            })
+    }
+}
+
+/// 短縮Weierstrass形式の点
+impl CurvePoint for Point {
+    fn identity(&self) -> Self {
+        Point {
+            x: None,
+            y: None,
+            a: self.a.clone(),
+            b: self.b.clone(),
+        }
+    }
+
+    fn is_identity(&self) -> bool {
+        self.is_infinity()
+    }
+
+    fn is_on_same_curve(&self, other: &Self) -> bool {
+        self.a == other.a && self.b == other.b
+    }
+
+    fn add_point(&self, other: &Self) -> Result<Self, PointError> {
+        self + other
+    }
+
+    fn negate(&self) -> Self {
+        -self.clone()
+    }
+
+    fn mul_secret(&self, scalar: &BigUint) -> Result<Self, PointError> {
+        self.clone() * scalar
+    }
+
+    fn mul_public(&self, scalar: &BigUint) -> Result<Self, PointError> {
+        self.mul_vartime(scalar.clone())
+    }
+
+    /// 圧縮SEC1形式
+    fn to_bytes(&self) -> Vec<u8> {
+        self.to_sec1(true)
+    }
+
+    fn linear_combination(terms: &[(Self, BigInt)]) -> Result<Self, PointError> {
+        Point::multi_scalar_mul(terms)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curve::Curve;
+
+    #[test]
+    fn test_point_group_operations() {
+        let curve = Curve::new(0, 7, 223).unwrap();
+        let p = curve.point(192, 105).unwrap();
+        let q = curve.point(47, 71).unwrap();
+
+        assert!(p.identity().is_identity());
+        assert!(p.add_point(&p.negate()).unwrap().is_identity());
+        assert_eq!(
+            p.mul_secret(&BigUint::from(42u32)).unwrap(),
+            p.mul_public(&BigUint::from(42u32)).unwrap()
+        );
+
+        // 5P - 3Q
+        let terms = [(p.clone(), BigInt::from(5)), (q.clone(), BigInt::from(-3))];
+        let expected = (p.clone() * 5)
+            .unwrap()
+            .add_point(&(q.clone() * 3).unwrap().negate())
+            .unwrap();
+        assert_eq!(Point::linear_combination(&terms).unwrap(), expected);
+
+        let other = Curve::new(1, 7, 223).unwrap().infinity_point();
+        assert!(!p.is_on_same_curve(&other));
+    }
+}
@@ -0,0 +1,458 @@
+use std::ops::{Add, Neg};
+
+use super::{conditional_swap, Point, PointError};
+use crate::field::FieldElement;
+
+/// ヤコビアン射影座標で表現された点
+///
+/// (X, Y, Z) はアフィン座標 (X/Z², Y/Z³) に対応し、Z = 0 が無限遠点を表す。
+/// 加算と2倍算で逆元計算が不要になるため、スカラー倍算の途中計算に用いる。
+#[derive(Debug, Clone)]
+pub struct JacobianPoint {
+    pub x: FieldElement,
+    pub y: FieldElement,
+    pub z: FieldElement,
+    pub a: FieldElement,
+    pub b: FieldElement,
+}
+
+/// JacobianPointの生成と操作を定義
+impl JacobianPoint {
+    /// 無限遠点 (1, 1, 0) を生成
+    pub fn infinity(a: FieldElement, b: FieldElement) -> Self {
+        let one = Self::constant(&a, 1);
+        let zero = Self::constant(&a, 0);
+        Self {
+            x: one.clone(),
+            y: one,
+            z: zero,
+            a,
+            b,
+        }
+    }
+
+    pub fn is_infinity(&self) -> bool {
+        self.z.is_zero()
+    }
+
+    /// アフィン座標に変換（逆元計算は1回）
+    pub fn to_affine(&self) -> Result<Point, PointError> {
+        if self.is_infinity() {
+            return Point::new(None, None, self.a.clone(), self.b.clone());
+        }
+
+        self.to_affine_with(&self.z.try_inv()?)
+    }
+
+    /// 複数の点をまとめてアフィン座標に変換
+    ///
+    /// Montgomeryの同時逆元計算により、点の個数によらず逆元計算は1回で済む。
+    pub fn batch_to_affine(points: &[Self]) -> Result<Vec<Point>, PointError> {
+        let Some(first) = points.first() else {
+            return Ok(Vec::new());
+        };
+
+        // prefix[k] = (k番目より前の有限な点の Z の積)
+        let finite: Vec<usize> = (0..points.len())
+            .filter(|&i| !points[i].is_infinity())
+            .collect();
+        let mut prefix = Vec::with_capacity(finite.len());
+        let mut product = Self::constant(&first.a, 1);
+        for &i in &finite {
+            prefix.push(product.clone());
+            product = &product * &points[i].z;
+        }
+
+        let mut affine = points
+            .iter()
+            .map(|point| Point::new(None, None, point.a.clone(), point.b.clone()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut inv = product.try_inv()?;
+        for (k, &i) in finite.iter().enumerate().rev() {
+            // inv = (Z₀⋯Z_k)⁻¹ から Z_k⁻¹ を取り出す
+            let z_inv = &inv * &prefix[k];
+            inv = &inv * &points[i].z;
+            affine[i] = points[i].to_affine_with(&z_inv)?;
+        }
+        Ok(affine)
+    }
+
+    /// Z⁻¹ を用いてアフィン座標に変換
+    fn to_affine_with(&self, z_inv: &FieldElement) -> Result<Point, PointError> {
+        let z_inv_squared = z_inv * z_inv;
+        let x = &self.x * &z_inv_squared;
+        let y = &self.y * &z_inv_squared * z_inv;
+        Point::new(Some(x), Some(y), self.a.clone(), self.b.clone())
+    }
+
+    /// 2倍算 (dbl-2007-bl)
+    pub fn double(&self) -> Self {
+        if self.is_infinity() {
+            return self.clone();
+        }
+
+        let xx = &self.x * &self.x;
+        let yy = &self.y * &self.y;
+        let yyyy = &yy * &yy;
+        let zz = &self.z * &self.z;
+
+        // S = 2((X + YY)² - XX - YYYY)
+        let x_plus_yy = &self.x + &yy;
+        let s = &x_plus_yy * &x_plus_yy - &xx - &yyyy;
+        let s = &s + &s;
+        // M = 3XX + aZZ²
+        let m = &xx + &xx + &xx + &self.a * &zz * &zz;
+        // X₃ = M² - 2S
+        let x3 = &m * &m - &s - &s;
+        // Y₃ = M(S - X₃) - 8YYYY
+        let yyyy_2 = &yyyy + &yyyy;
+        let yyyy_4 = &yyyy_2 + &yyyy_2;
+        let y3 = &m * (&s - &x3) - &yyyy_4 - &yyyy_4;
+        // Z₃ = (Y + Z)² - YY - ZZ
+        let y_plus_z = &self.y + &self.z;
+        let z3 = &y_plus_z * &y_plus_z - &yy - &zz;
+
+        Self {
+            x: x3,
+            y: y3,
+            z: z3,
+            a: self.a.clone(),
+            b: self.b.clone(),
+        }
+    }
+
+    /// アフィン座標の点との混合加算 (madd-2007-bl)
+    pub fn add_mixed(&self, other: &Point) -> Result<Self, PointError> {
+        if self.a != other.a || self.b != other.b {
+            return Err(PointError::DifferentCurves);
+        }
+
+        let (x2, y2) = match (&other.x, &other.y) {
+            (Some(x), Some(y)) => (x, y),
+            _ => return Ok(self.clone()),
+        };
+        if self.is_infinity() {
+            return Ok(Self::from(other));
+        }
+
+        let z1z1 = &self.z * &self.z;
+        let u2 = x2 * &z1z1;
+        let s2 = y2 * &self.z * &z1z1;
+        let h = &u2 - &self.x;
+        let r = &s2 - &self.y;
+
+        if h.is_zero() {
+            // 同じx座標: P = Q なら2倍算、P = -Q なら無限遠点
+            return Ok(if r.is_zero() {
+                self.double()
+            } else {
+                Self::infinity(self.a.clone(), self.b.clone())
+            });
+        }
+
+        let hh = &h * &h;
+        let i = &hh + &hh;
+        let i = &i + &i;
+        let j = &h * &i;
+        let r = &r + &r;
+        let v = &self.x * &i;
+
+        let x3 = &r * &r - &j - &v - &v;
+        let y1_j = &self.y * &j;
+        let y3 = &r * (&v - &x3) - &y1_j - &y1_j;
+        let z1_plus_h = &self.z + &h;
+        let z3 = &z1_plus_h * &z1_plus_h - &z1z1 - &hh;
+
+        Ok(Self {
+            x: x3,
+            y: y3,
+            z: z3,
+            a: self.a.clone(),
+            b: self.b.clone(),
+        })
+    }
+
+    /// 例外ケースでも同じ演算列を実行する混合加算
+    ///
+    /// 通常の加算・2倍算・無限遠点の結果をすべて計算し、条件付き交換で1つを選ぶ。
+    /// `other` が無限遠点の場合は座標 (0, 0) で計算し、その結果は捨てる。
+    /// ただし多倍長整数演算そのものは定数時間ではない。
+    pub fn add_mixed_regular(&self, other: &Point) -> Result<Self, PointError> {
+        if self.a != other.a || self.b != other.b {
+            return Err(PointError::DifferentCurves);
+        }
+
+        let zero = Self::constant(&self.a, 0);
+        let (x2, y2) = match (&other.x, &other.y) {
+            (Some(x), Some(y)) => (x.clone(), y.clone()),
+            _ => (zero.clone(), zero),
+        };
+
+        let z1z1 = &self.z * &self.z;
+        let u2 = &x2 * &z1z1;
+        let s2 = &y2 * &self.z * &z1z1;
+        let h = &u2 - &self.x;
+        let r = &s2 - &self.y;
+        let same_x = h.is_zero();
+        let same_y = r.is_zero();
+
+        let hh = &h * &h;
+        let i = &hh + &hh;
+        let i = &i + &i;
+        let j = &h * &i;
+        let r = &r + &r;
+        let v = &self.x * &i;
+
+        let x3 = &r * &r - &j - &v - &v;
+        let y1_j = &self.y * &j;
+        let y3 = &r * (&v - &x3) - &y1_j - &y1_j;
+        let z1_plus_h = &self.z + &h;
+        let z3 = &z1_plus_h * &z1_plus_h - &z1z1 - &hh;
+
+        let mut result = Self {
+            x: x3,
+            y: y3,
+            z: z3,
+            a: self.a.clone(),
+            b: self.b.clone(),
+        };
+        // P = Q なら2倍算、P = -Q なら無限遠点
+        conditional_swap(&mut result, &mut self.double(), same_x && same_y);
+        conditional_swap(
+            &mut result,
+            &mut Self::infinity(self.a.clone(), self.b.clone()),
+            same_x && !same_y,
+        );
+        // どちらかが無限遠点ならもう一方
+        conditional_swap(&mut result, &mut self.clone(), other.x.is_none());
+        conditional_swap(&mut result, &mut Self::from(other), self.is_infinity());
+        Ok(result)
+    }
+
+    /// 定数を曲線の体の元として生成
+    fn constant(a: &FieldElement, value: i64) -> FieldElement {
+        FieldElement::new(value, a.prime().clone()).expect("curve prime is valid")
+    }
+}
+
+impl From<&Point> for JacobianPoint {
+    fn from(point: &Point) -> Self {
+        match (&point.x, &point.y) {
+            (Some(x), Some(y)) => Self {
+                x: x.clone(),
+                y: y.clone(),
+                z: Self::constant(&point.a, 1),
+                a: point.a.clone(),
+                b: point.b.clone(),
+            },
+            _ => Self::infinity(point.a.clone(), point.b.clone()),
+        }
+    }
+}
+
+impl From<Point> for JacobianPoint {
+    fn from(point: Point) -> Self {
+        Self::from(&point)
+    }
+}
+
+/// 射影座標のまま同じ点かどうかを比較
+impl PartialEq for JacobianPoint {
+    fn eq(&self, other: &Self) -> bool {
+        if self.a != other.a || self.b != other.b {
+            return false;
+        }
+        if self.is_infinity() || other.is_infinity() {
+            return self.is_infinity() && other.is_infinity();
+        }
+
+        // X₁Z₂² = X₂Z₁² かつ Y₁Z₂³ = Y₂Z₁³
+        let z1z1 = &self.z * &self.z;
+        let z2z2 = &other.z * &other.z;
+        &self.x * &z2z2 == &other.x * &z1z1
+            && &self.y * &z2z2 * &other.z == &other.y * &z1z1 * &self.z
+    }
+}
+
+/// JacobianPointの加算を定義 (add-2007-bl)
+impl Add<&JacobianPoint> for &JacobianPoint {
+    type Output = Result<JacobianPoint, PointError>;
+
+    fn add(self, other: &JacobianPoint) -> Result<JacobianPoint, PointError> {
+        if self.a != other.a || self.b != other.b {
+            return Err(PointError::DifferentCurves);
+        }
+        if self.is_infinity() {
+            return Ok(other.clone());
+        }
+        if other.is_infinity() {
+            return Ok(self.clone());
+        }
+
+        let z1z1 = &self.z * &self.z;
+        let z2z2 = &other.z * &other.z;
+        let u1 = &self.x * &z2z2;
+        let u2 = &other.x * &z1z1;
+        let s1 = &self.y * &other.z * &z2z2;
+        let s2 = &other.y * &self.z * &z1z1;
+        let h = &u2 - &u1;
+        let r = &s2 - &s1;
+
+        if h.is_zero() {
+            return Ok(if r.is_zero() {
+                self.double()
+            } else {
+                JacobianPoint::infinity(self.a.clone(), self.b.clone())
+            });
+        }
+
+        let h2 = &h + &h;
+        let i = &h2 * &h2;
+        let j = &h * &i;
+        let r = &r + &r;
+        let v = &u1 * &i;
+
+        let x3 = &r * &r - &j - &v - &v;
+        let s1_j = &s1 * &j;
+        let y3 = &r * (&v - &x3) - &s1_j - &s1_j;
+        let z_sum = &self.z + &other.z;
+        let z3 = (&z_sum * &z_sum - &z1z1 - &z2z2) * &h;
+
+        Ok(JacobianPoint {
+            x: x3,
+            y: y3,
+            z: z3,
+            a: self.a.clone(),
+            b: self.b.clone(),
+        })
+    }
+}
+
+impl Add for JacobianPoint {
+    type Output = Result<Self, PointError>;
+
+    fn add(self, other: Self) -> Result<Self, PointError> {
+        &self + &other
+    }
+}
+
+/// JacobianPointの符号を反転
+impl Neg for JacobianPoint {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self { y: -self.y, ..self }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curve::Curve;
+
+    fn setup() -> (Curve, Point, Point) {
+        let curve = Curve::new(0, 7, 223).unwrap();
+        let p = curve.point(192, 105).unwrap();
+        let q = curve.point(17, 56).unwrap();
+        (curve, p, q)
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let (curve, p, _) = setup();
+        assert_eq!(JacobianPoint::from(&p).to_affine().unwrap(), p);
+
+        let infinity = JacobianPoint::from(curve.infinity_point());
+        assert!(infinity.is_infinity());
+        assert!(infinity.to_affine().unwrap().is_infinity());
+    }
+
+    #[test]
+    fn test_double_matches_affine() {
+        let (_, p, _) = setup();
+        let doubled = JacobianPoint::from(&p).double();
+        assert_eq!(doubled.to_affine().unwrap(), (&p + &p).unwrap());
+    }
+
+    #[test]
+    fn test_add_matches_affine() {
+        let (_, p, q) = setup();
+        let expected = (&p + &q).unwrap();
+
+        // Z ≠ 1 の点同士でも同じ結果になることを確認
+        let jp = JacobianPoint::from(&p).double();
+        let jq = JacobianPoint::from(&q);
+        let sum = (&jp + &jq).unwrap();
+        assert_eq!(
+            sum.to_affine().unwrap(),
+            ((&p + &p).unwrap() + q.clone()).unwrap()
+        );
+
+        let mixed = JacobianPoint::from(&p).add_mixed(&q).unwrap();
+        assert_eq!(mixed.to_affine().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_special_cases() {
+        let (curve, p, _) = setup();
+        let jp = JacobianPoint::from(&p);
+
+        // P + P は2倍算に、P + (-P) は無限遠点になる
+        assert_eq!((&jp + &jp).unwrap(), jp.double());
+        assert!(jp.add_mixed(&-p.clone()).unwrap().is_infinity());
+        assert!((&jp + &(-jp.clone())).unwrap().is_infinity());
+
+        // 無限遠点は単位元
+        let infinity = JacobianPoint::from(curve.infinity_point());
+        assert_eq!((&infinity + &jp).unwrap(), jp);
+        assert_eq!(infinity.add_mixed(&p).unwrap(), jp);
+        assert_eq!(jp.add_mixed(&curve.infinity_point()).unwrap(), jp);
+    }
+
+    #[test]
+    fn test_add_mixed_regular_matches_add_mixed() {
+        let (curve, p, q) = setup();
+        let jp = JacobianPoint::from(&p).double();
+        let infinity = JacobianPoint::from(curve.infinity_point());
+        let doubled_p = jp.to_affine().unwrap();
+
+        // 通常の加算・2倍算・逆元・無限遠点のすべてで結果が一致する
+        for (left, right) in [
+            (&jp, &q),
+            (&jp, &doubled_p),
+            (&jp, &-doubled_p.clone()),
+            (&jp, &curve.infinity_point()),
+            (&infinity, &q),
+            (&infinity, &curve.infinity_point()),
+        ] {
+            assert_eq!(
+                left.add_mixed_regular(right).unwrap(),
+                left.add_mixed(right).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_batch_to_affine() {
+        let (curve, p, q) = setup();
+        let points = [
+            JacobianPoint::from(&p).double(),
+            JacobianPoint::from(curve.infinity_point()),
+            (&JacobianPoint::from(&p) + &JacobianPoint::from(&q)).unwrap(),
+            JacobianPoint::from(&q),
+        ];
+        let expected: Vec<Point> = points.iter().map(|p| p.to_affine().unwrap()).collect();
+        assert_eq!(JacobianPoint::batch_to_affine(&points).unwrap(), expected);
+        assert!(JacobianPoint::batch_to_affine(&[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_different_curves() {
+        let (_, p, _) = setup();
+        let other = Curve::new(1, 7, 223).unwrap().infinity_point();
+        assert!(matches!(
+            JacobianPoint::from(&p).add_mixed(&other),
+            Err(PointError::DifferentCurves)
+        ));
+    }
+}
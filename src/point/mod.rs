@@ -0,0 +1,494 @@
+use num_bigint::{BigInt, BigUint};
+use num_traits::Signed;
+use std::ops::{Add, Mul, Neg};
+use thiserror::Error;
+
+use crate::field::FieldElement;
+
+pub mod fixed_base;
+mod group;
+pub mod jacobian;
+mod msm;
+pub mod wnaf;
+
+pub use fixed_base::FixedBaseTable;
+pub use group::CurvePoint;
+pub use jacobian::JacobianPoint;
+
+#[derive(Error, Debug)]
+pub enum PointError {
+    #[error("Point is not on the curve")]
+    NotOnCurve,
+    #[error("Cannot perform operation with points on different curves")]
+    DifferentCurves,
+    #[error("Window size is out of range")]
+    InvalidWindowSize,
+    #[error("Order does not annihilate the base point")]
+    InvalidOrder,
+    #[error("At least one term is required")]
+    EmptyInput,
+    #[error("Addition law is not defined for these points")]
+    IncompleteAddition,
+    #[error("Field error: {0}")]
+    FieldError(#[from] crate::field::FieldError),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Point {
+    pub x: Option<FieldElement>,
+    pub y: Option<FieldElement>,
+    pub a: FieldElement,
+    pub b: FieldElement,
+}
+
+/// Pointの生成と操作を定義
+impl Point {
+    pub fn new(
+        x: Option<FieldElement>,
+        y: Option<FieldElement>,
+        a: FieldElement,
+        b: FieldElement,
+    ) -> Result<Self, PointError> {
+        // 無限遠点の場合
+        if x.is_none() && y.is_none() {
+            return Ok(Self { x, y, a, b });
+        }
+
+        // 点が曲線上にあるか確認
+        if let (Some(x_val), Some(y_val)) = (x.as_ref(), y.as_ref()) {
+            let y_squared = y_val.checked_mul(y_val)?;
+            let x_cubed = x_val.checked_mul(x_val)?.checked_mul(x_val)?;
+            let ax = a.checked_mul(x_val)?;
+
+            if y_squared == x_cubed.checked_add(&ax)?.checked_add(&b)? {
+                Ok(Self { x, y, a, b })
+            } else {
+                Err(PointError::NotOnCurve)
+            }
+        } else {
+            Err(PointError::NotOnCurve)
+        }
+    }
+
+    pub fn is_infinity(&self) -> bool {
+        self.x.is_none() && self.y.is_none()
+    }
+
+    /// SEC1形式にエンコード
+    ///
+    /// 圧縮形式は `0x02`/`0x03` + x、非圧縮形式は `0x04` + x + y。
+    /// 無限遠点は1バイトの `0x00` になる。
+    pub fn to_sec1(&self, compressed: bool) -> Vec<u8> {
+        let (x, y) = match (&self.x, &self.y) {
+            (Some(x), Some(y)) => (x, y),
+            _ => return vec![0x00],
+        };
+
+        let mut encoded = Vec::with_capacity(1 + 2 * x.byte_len());
+        if compressed {
+            encoded.push(if y.value().bit(0) { 0x03 } else { 0x02 });
+            encoded.extend(x.to_bytes_be());
+        } else {
+            encoded.push(0x04);
+            encoded.extend(x.to_bytes_be());
+            encoded.extend(y.to_bytes_be());
+        }
+        encoded
+    }
+}
+
+/// Pointの加算を定義
+impl Add<&Point> for &Point {
+    type Output = Result<Point, PointError>;
+
+    fn add(self, other: &Point) -> Result<Point, PointError> {
+        if self.a != other.a || self.b != other.b {
+            return Err(PointError::DifferentCurves);
+        }
+
+        // 無限遠点の場合
+        let (x1, y1) = match (&self.x, &self.y) {
+            (Some(x), Some(y)) => (x, y),
+            _ => return Ok(other.clone()),
+        };
+        let (x2, y2) = match (&other.x, &other.y) {
+            (Some(x), Some(y)) => (x, y),
+            _ => return Ok(self.clone()),
+        };
+
+        // P + (-P) = O
+        if x1 == x2 && y1.checked_add(y2)?.is_zero() {
+            return Point::new(None, None, self.a.clone(), self.b.clone());
+        }
+
+        // スロープを計算
+        let slope = if x1 == x2 && y1 == y2 {
+            // s = (3x₁² + a) / 2y₁
+            let three = FieldElement::new(3, x1.prime().clone())?;
+            let numerator = x1
+                .checked_mul(x1)?
+                .checked_mul(&three)?
+                .checked_add(&self.a)?;
+            let denominator = y1.checked_add(y1)?;
+            numerator.checked_div(&denominator)?
+        } else {
+            // s = (y₂ - y₁) / (x₂ - x₁)
+            y2.checked_sub(y1)?.checked_div(&x2.checked_sub(x1)?)?
+        };
+
+        // x₃ = s² - x₁ - x₂
+        let x3 = slope
+            .checked_mul(&slope)?
+            .checked_sub(x1)?
+            .checked_sub(x2)?;
+        let y3 = slope.checked_mul(&x1.checked_sub(&x3)?)?.checked_sub(y1)?;
+
+        Point::new(Some(x3), Some(y3), self.a.clone(), self.b.clone())
+    }
+}
+
+impl Add for Point {
+    type Output = Result<Self, PointError>;
+
+    fn add(self, other: Self) -> Result<Self, PointError> {
+        &self + &other
+    }
+}
+
+/// Pointのスカラー乗算を定義
+impl Neg for Point {
+    type Output = Self;
+
+    /// Pointの符号を反転
+    fn neg(self) -> Self {
+        if self.is_infinity() {
+            return self;
+        }
+
+        Self {
+            x: self.x,
+            y: self.y.map(|y| -y),
+            a: self.a,
+            b: self.b,
+        }
+    }
+}
+
+/// Pointのスカラー乗算を定義
+impl Point {
+    /// 公開スカラー用の可変時間スカラー倍算（wNAF）
+    ///
+    /// スカラーの各桁で分岐するため、実行時間や演算列からスカラーが漏れる。
+    /// 位数の検証など公開値にのみ使用し、秘密鍵には `*` 演算子を使うこと。
+    pub fn mul_vartime(&self, scalar: impl Into<BigInt>) -> Result<Self, PointError> {
+        self.mul_wnaf(scalar, wnaf::DEFAULT_WINDOW)
+    }
+
+    /// Montgomeryラダーによるスカラー倍算
+    ///
+    /// Jacobian座標でラダーを回し、最後に1回だけアフィン座標へ正規化する。
+    fn mul_ladder(&self, scalar: &BigInt) -> Result<Self, PointError> {
+        let identity = JacobianPoint::infinity(self.a.clone(), self.b.clone());
+        ladder(
+            JacobianPoint::from(self),
+            identity,
+            scalar,
+            self.a.prime(),
+            |point| Ok(point.double()),
+        )?
+        .to_affine()
+    }
+}
+
+/// `swap` が真のとき2つの値を交換
+///
+/// Montgomeryラダーなどの演算列を秘密ビットに依存させないための補助関数。
+/// ただし多倍長整数演算そのものは定数時間ではない。
+pub(crate) fn conditional_swap<T: Clone>(a: &mut T, b: &mut T, swap: bool) {
+    let mut pair = [a.clone(), b.clone()];
+    // 分岐せずに添字で選択する
+    pair.swap(0, swap as usize);
+    let [first, second] = pair;
+    *a = first;
+    *b = second;
+}
+
+/// 公開スカラーによる可変時間のスカラー倍算（double-and-add）
+///
+/// 加算と符号反転だけを持つ点の型（Edwards、Montgomery、一般Weierstrass形式）で共通に使う。
+pub(crate) fn double_and_add<T>(point: &T, identity: T, scalar: &BigInt) -> Result<T, PointError>
+where
+    T: Neg<Output = T>,
+    for<'a> &'a T: Add<&'a T, Output = Result<T, PointError>>,
+{
+    let k = scalar.magnitude();
+    let mut result = identity;
+    for bit in (0..k.bits()).rev() {
+        result = (&result + &result)?;
+        if k.bit(bit) {
+            result = (&result + point)?;
+        }
+    }
+    Ok(if scalar.is_negative() {
+        -result
+    } else {
+        result
+    })
+}
+
+/// 秘密スカラーによるスカラー倍算（Montgomeryラダー）
+///
+/// スカラーの値に関わらず、固定長のビット列に対して
+/// 「条件付き交換・加算・2倍算・条件付き交換」の同じ演算列を実行する。
+/// ビット長は群の位数の上限 (Hasseの定理より p + 1 + 2√p < 2^(log₂p + 1)) に揃える。
+/// 2倍算は点の型ごとの公式を `double` で渡す。
+pub(crate) fn ladder<T>(
+    point: T,
+    identity: T,
+    scalar: &BigInt,
+    prime: &BigUint,
+    double: impl Fn(&T) -> Result<T, PointError>,
+) -> Result<T, PointError>
+where
+    T: Clone + Neg<Output = T>,
+    for<'a> &'a T: Add<&'a T, Output = Result<T, PointError>>,
+{
+    let k = scalar.magnitude();
+    let bits = k.bits().max(prime.bits() + 1);
+    let mut r0 = identity;
+    let mut r1 = point;
+
+    for bit in (0..bits).rev() {
+        let swap = k.bit(bit);
+        conditional_swap(&mut r0, &mut r1, swap);
+        r1 = (&r0 + &r1)?;
+        r0 = double(&r0)?;
+        conditional_swap(&mut r0, &mut r1, swap);
+    }
+
+    Ok(if scalar.is_negative() { -r0 } else { r0 })
+}
+
+/// 秘密スカラーを想定し、Montgomeryラダーで計算する
+impl Mul<i64> for Point {
+    type Output = Result<Self, PointError>;
+
+    fn mul(self, scalar: i64) -> Result<Self, PointError> {
+        self.mul_ladder(&BigInt::from(scalar))
+    }
+}
+
+/// 多倍長整数のスカラー乗算を定義
+impl Mul<&BigInt> for Point {
+    type Output = Result<Self, PointError>;
+
+    fn mul(self, scalar: &BigInt) -> Result<Self, PointError> {
+        self.mul_ladder(scalar)
+    }
+}
+
+/// 多倍長の非負整数のスカラー乗算を定義
+impl Mul<&BigUint> for Point {
+    type Output = Result<Self, PointError>;
+
+    fn mul(self, scalar: &BigUint) -> Result<Self, PointError> {
+        self.mul_ladder(&BigInt::from(scalar.clone()))
+    }
+}
+
+/// 部分群に関する操作を定義
+impl Point {
+    /// 余因子を掛けて素数位数の部分群へ写す
+    ///
+    /// 余因子 h は `Curve::cofactor` などで求めた #E / n を渡す。
+    pub fn clear_cofactor(&self, cofactor: &BigUint) -> Result<Self, PointError> {
+        self.mul_vartime(cofactor.clone())
+    }
+
+    /// 点が位数 n の部分群に含まれるか（n·P = O か）を判定
+    pub fn is_in_prime_order_subgroup(&self, n: &BigUint) -> bool {
+        self.mul_vartime(n.clone())
+            .is_ok_and(|point| point.is_infinity())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::field::{FieldElement, FieldError};
+
+    fn create_test_point() -> Point {
+        // Using curve y² = x³ + 7 over F223
+        let prime = 223;
+        let a = FieldElement::new(0, prime).unwrap();
+        let b = FieldElement::new(7, prime).unwrap();
+        let x = FieldElement::new(192, prime).unwrap();
+        let y = FieldElement::new(105, prime).unwrap();
+        Point::new(Some(x), Some(y), a, b).unwrap()
+    }
+
+    #[test]
+    fn test_point_creation() {
+        let point = create_test_point();
+        assert!(!point.is_infinity());
+    }
+
+    #[test]
+    fn test_point_addition() {
+        // Using curve y² = x³ + 7 over F223
+        let prime = 223;
+        let a = FieldElement::new(0, prime).unwrap();
+        let b = FieldElement::new(7, prime).unwrap();
+
+        // Point 1: (192, 105)
+        let x1 = FieldElement::new(192, prime).unwrap();
+        let y1 = FieldElement::new(105, prime).unwrap();
+        let p1 = Point::new(Some(x1), Some(y1), a.clone(), b.clone()).unwrap();
+
+        // Point 2: (17, 56)
+        let x2 = FieldElement::new(17, prime).unwrap();
+        let y2 = FieldElement::new(56, prime).unwrap();
+        let p2 = Point::new(Some(x2), Some(y2), a, b).unwrap();
+
+        // Expected result: (170, 142)
+        let result = (p1 + p2).unwrap();
+        assert_eq!(result.x.unwrap().value(), &BigUint::from(170u32));
+        assert_eq!(result.y.unwrap().value(), &BigUint::from(142u32));
+    }
+
+    #[test]
+    fn test_point_doubling() {
+        let prime = 223;
+        let a = FieldElement::new(0, prime).unwrap();
+        let b = FieldElement::new(7, prime).unwrap();
+
+        // Point: (192, 105)
+        let x = FieldElement::new(192, prime).unwrap();
+        let y = FieldElement::new(105, prime).unwrap();
+        let p = Point::new(Some(x), Some(y), a, b).unwrap();
+
+        // Double the point
+        let result = (p.clone() + p).unwrap();
+
+        // Expected result: (49, 71)
+        assert_eq!(result.x.unwrap().value(), &BigUint::from(49u32));
+        assert_eq!(result.y.unwrap().value(), &BigUint::from(71u32));
+    }
+
+    #[test]
+    fn test_scalar_multiplication() {
+        let prime = 223;
+        let a = FieldElement::new(0, prime).unwrap();
+        let b = FieldElement::new(7, prime).unwrap();
+
+        // Point: (192, 105)
+        let x = FieldElement::new(192, prime).unwrap();
+        let y = FieldElement::new(105, prime).unwrap();
+        let p = Point::new(Some(x), Some(y), a, b).unwrap();
+
+        // Multiply by 2
+        let result = (p * 2).unwrap();
+        assert_eq!(result.x.unwrap().value(), &BigUint::from(49u32));
+        assert_eq!(result.y.unwrap().value(), &BigUint::from(71u32));
+    }
+
+    #[test]
+    fn test_point_negation() {
+        let point = create_test_point();
+        let neg_point = -point;
+
+        assert_eq!(neg_point.x.unwrap().value(), &BigUint::from(192u32));
+        assert_eq!(neg_point.y.unwrap().value(), &BigUint::from(223u32 - 105)); // -105 mod 223
+    }
+
+    #[test]
+    fn test_mismatched_fields_return_error() {
+        // 曲線係数と座標の体が異なる場合はパニックせずエラーを返す
+        let a = FieldElement::new(0, 223).unwrap();
+        let b = FieldElement::new(7, 223).unwrap();
+        let x = FieldElement::new(192, 227).unwrap();
+        let y = FieldElement::new(105, 227).unwrap();
+        assert!(matches!(
+            Point::new(Some(x), Some(y), a, b),
+            Err(PointError::FieldError(FieldError::MismatchedFields))
+        ));
+    }
+
+    #[test]
+    fn test_add_malformed_point_returns_error() {
+        let p = create_test_point();
+        // 検証を経ずに作られた、別の体の座標を持つ点
+        let malformed = Point {
+            x: Some(FieldElement::new(17, 227).unwrap()),
+            y: Some(FieldElement::new(56, 227).unwrap()),
+            a: p.a.clone(),
+            b: p.b.clone(),
+        };
+        assert!(matches!(
+            &p + &malformed,
+            Err(PointError::FieldError(FieldError::MismatchedFields))
+        ));
+    }
+
+    #[test]
+    fn test_scalar_multiplication_matches_repeated_addition() {
+        let p = create_test_point();
+        let mut expected = Point::new(None, None, p.a.clone(), p.b.clone()).unwrap();
+        for k in 0..=30 {
+            assert_eq!((p.clone() * k).unwrap(), expected, "k = {k}");
+            expected = (&expected + &p).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_ladder_matches_vartime() {
+        let p = create_test_point();
+        for k in [0, 1, 2, 3, 20, 21, 42, 1 << 40, i64::MAX] {
+            assert_eq!(
+                (p.clone() * k).unwrap(),
+                p.mul_vartime(k).unwrap(),
+                "k = {k}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_negative_scalar_multiplication() {
+        let p = create_test_point();
+        for k in [1, 2, 7, 21, 42] {
+            let expected = -(p.clone() * k).unwrap();
+            assert_eq!((p.clone() * -k).unwrap(), expected, "k = {k}");
+            assert_eq!(p.mul_vartime(-k).unwrap(), expected, "k = {k}");
+        }
+        assert_eq!(
+            (p.clone() * i64::MIN).unwrap(),
+            p.mul_vartime(i64::MIN).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_big_integer_scalar_multiplication() {
+        let p = create_test_point();
+        // (192, 105) の位数は 42 で、2²⁰⁰ + 5 ≡ 9 (mod 42)
+        let k: BigUint = (BigUint::from(1u32) << 200) + 5u32;
+        let expected = (p.clone() * 9).unwrap();
+
+        assert_eq!((p.clone() * &k).unwrap(), expected);
+        assert_eq!((p.clone() * &BigInt::from(k.clone())).unwrap(), expected);
+        assert_eq!((p.clone() * &-BigInt::from(k)).unwrap(), -expected);
+        assert!((p * &BigUint::from(42u32)).unwrap().is_infinity());
+    }
+
+    #[test]
+    fn test_clear_cofactor() {
+        // 位数42の点に 42 / 7 = 6 を掛けると位数7の部分群に入る
+        let p = create_test_point();
+        let seven = BigUint::from(7u32);
+        assert!(!p.is_in_prime_order_subgroup(&seven));
+
+        let cleared = p.clear_cofactor(&BigUint::from(6u32)).unwrap();
+        assert!(!cleared.is_infinity());
+        assert!(cleared.is_in_prime_order_subgroup(&seven));
+        assert_eq!(cleared, (p * 6).unwrap());
+    }
+}
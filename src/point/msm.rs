@@ -0,0 +1,197 @@
+use num_bigint::{BigInt, BigUint};
+use num_traits::Signed;
+
+use super::wnaf::wnaf_digits;
+use super::{JacobianPoint, Point, PointError};
+
+/// この項数以上ではPippengerのバケット法を使う
+const PIPPENGER_THRESHOLD: usize = 32;
+
+/// Strausの方法で使うwNAFのウィンドウ幅
+const STRAUS_WINDOW: usize = 4;
+
+/// 複数スカラー倍算を定義
+impl Point {
+    /// Σ kᵢ·Pᵢ を一度に計算
+    ///
+    /// 項数が少ない場合はStrausの方法（wNAFで2倍算を共有）、
+    /// 多い場合はPippengerのバケット法を用いる。
+    /// スカラーに応じて分岐するため可変時間であり、署名検証など公開スカラーに使用すること。
+    pub fn multi_scalar_mul(terms: &[(Point, BigInt)]) -> Result<Point, PointError> {
+        let first = terms.first().ok_or(PointError::EmptyInput)?;
+        if terms
+            .iter()
+            .any(|(point, _)| point.a != first.0.a || point.b != first.0.b)
+        {
+            return Err(PointError::DifferentCurves);
+        }
+
+        // 負のスカラーは点の符号に移す
+        let normalized: Vec<(Point, BigUint)> = terms
+            .iter()
+            .map(|(point, scalar)| {
+                let point = if scalar.is_negative() {
+                    -point.clone()
+                } else {
+                    point.clone()
+                };
+                (point, scalar.magnitude().clone())
+            })
+            .collect();
+
+        let result = if normalized.len() < PIPPENGER_THRESHOLD {
+            straus(&normalized)?
+        } else {
+            pippenger(&normalized)?
+        };
+        result.to_affine()
+    }
+}
+
+/// Strausの方法 (interleaved wNAF)
+fn straus(terms: &[(Point, BigUint)]) -> Result<JacobianPoint, PointError> {
+    let (first, _) = &terms[0];
+
+    // 各点の奇数倍 P, 3P, …, (2^(w-1) - 1)P と wNAF 表現を用意する
+    let mut tables = Vec::with_capacity(terms.len());
+    let mut digits = Vec::with_capacity(terms.len());
+    for (point, scalar) in terms {
+        let base = JacobianPoint::from(point);
+        let twice = base.double();
+        let mut table = vec![base];
+        for i in 1..(1 << (STRAUS_WINDOW - 2)) {
+            let next = (&table[i - 1] + &twice)?;
+            table.push(next);
+        }
+        tables.push(table);
+        digits.push(wnaf_digits(scalar, STRAUS_WINDOW));
+    }
+
+    let length = digits.iter().map(Vec::len).max().unwrap_or(0);
+    let mut result = JacobianPoint::infinity(first.a.clone(), first.b.clone());
+    for i in (0..length).rev() {
+        result = result.double();
+        for (table, naf) in tables.iter().zip(&digits) {
+            match naf.get(i).copied().unwrap_or(0) {
+                0 => {}
+                d if d > 0 => result = (&result + &table[(d as usize - 1) / 2])?,
+                d => {
+                    let negated = -table[((-d) as usize - 1) / 2].clone();
+                    result = (&result + &negated)?;
+                }
+            }
+        }
+    }
+
+    Ok(result)
+}
+
+/// Pippengerのバケット法
+fn pippenger(terms: &[(Point, BigUint)]) -> Result<JacobianPoint, PointError> {
+    let (first, _) = &terms[0];
+    let infinity = JacobianPoint::infinity(first.a.clone(), first.b.clone());
+
+    // ウィンドウ幅は項数の対数程度にとる
+    let window = (usize::BITS - terms.len().leading_zeros())
+        .saturating_sub(2)
+        .max(1) as u64;
+    let bits = terms.iter().map(|(_, k)| k.bits()).max().unwrap_or(0);
+    let windows = bits.div_ceil(window);
+
+    let mut result = infinity.clone();
+    for w in (0..windows).rev() {
+        for _ in 0..window {
+            result = result.double();
+        }
+
+        // 桁の値ごとにバケットへ点を振り分ける
+        let mut buckets = vec![infinity.clone(); (1 << window) - 1];
+        for (point, scalar) in terms {
+            let mut digit = 0usize;
+            for bit in 0..window {
+                digit |= (scalar.bit(w * window + bit) as usize) << bit;
+            }
+            if digit != 0 {
+                buckets[digit - 1] = buckets[digit - 1].add_mixed(point)?;
+            }
+        }
+
+        // Σ j·B_j を累積和2回で求める
+        let mut running = infinity.clone();
+        let mut window_sum = infinity.clone();
+        for bucket in buckets.iter().rev() {
+            running = (&running + bucket)?;
+            window_sum = (&window_sum + &running)?;
+        }
+        result = (&result + &window_sum)?;
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curve::Curve;
+
+    /// 個別に乗算して足し合わせた結果
+    fn naive(terms: &[(Point, BigInt)]) -> Point {
+        terms
+            .iter()
+            .fold(terms[0].0.mul_vartime(0).unwrap(), |acc, (point, k)| {
+                (acc + (point.clone() * k).unwrap()).unwrap()
+            })
+    }
+
+    fn sample_terms(count: usize) -> Vec<(Point, BigInt)> {
+        let curve = Curve::new(0, 7, 223).unwrap();
+        let g = curve.point(192, 105).unwrap();
+        (0..count as i64)
+            .map(|i| {
+                let point = g.mul_vartime(i * 7 + 1).unwrap();
+                let scalar = BigInt::from((i * 37 + 11) % 101 - 50);
+                (point, scalar)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_multi_scalar_mul_small_batch() {
+        for count in 1..6 {
+            let terms = sample_terms(count);
+            assert_eq!(Point::multi_scalar_mul(&terms).unwrap(), naive(&terms));
+        }
+    }
+
+    #[test]
+    fn test_multi_scalar_mul_large_batch() {
+        let terms = sample_terms(PIPPENGER_THRESHOLD + 8);
+        assert_eq!(Point::multi_scalar_mul(&terms).unwrap(), naive(&terms));
+    }
+
+    #[test]
+    fn test_straus_and_pippenger_agree() {
+        let terms: Vec<(Point, BigUint)> = sample_terms(10)
+            .into_iter()
+            .map(|(p, k)| (p, k.magnitude().clone()))
+            .collect();
+        let straus = straus(&terms).unwrap();
+        let pippenger = pippenger(&terms).unwrap();
+        assert_eq!(straus, pippenger);
+    }
+
+    #[test]
+    fn test_multi_scalar_mul_errors() {
+        assert!(matches!(
+            Point::multi_scalar_mul(&[]),
+            Err(PointError::EmptyInput)
+        ));
+
+        let p = Curve::new(0, 7, 223).unwrap().point(192, 105).unwrap();
+        let q = Curve::new(1, 7, 223).unwrap().infinity_point();
+        assert!(matches!(
+            Point::multi_scalar_mul(&[(p, BigInt::from(1)), (q, BigInt::from(1))]),
+            Err(PointError::DifferentCurves)
+        ));
+    }
+}
@@ -0,0 +1,136 @@
+use num_bigint::{BigInt, BigUint};
+use num_integer::Integer;
+use num_traits::{Signed, Zero};
+
+use super::{JacobianPoint, Point, PointError};
+
+/// `mul_vartime` で使用する既定のウィンドウ幅
+pub const DEFAULT_WINDOW: usize = 4;
+
+/// スカラーを幅 w のNAF (wNAF) 表現に変換
+///
+/// 下位桁から順に並び、各桁は0または絶対値が 2^(w-1) 未満の奇数になる。
+/// 0でない桁の間には少なくとも w-1 個の0が入る。
+pub fn wnaf_digits(scalar: &BigUint, window: usize) -> Vec<i8> {
+    let modulus = BigInt::from(1u32) << window;
+    let half = BigInt::from(1u32) << (window - 1);
+    let mut k = BigInt::from(scalar.clone());
+    let mut digits = Vec::with_capacity(scalar.bits() as usize + 1);
+
+    while !k.is_zero() {
+        let digit = if k.is_odd() {
+            // k mods 2^w を (-2^(w-1), 2^(w-1)) の範囲で取る
+            let mut d = k.mod_floor(&modulus);
+            if d >= half {
+                d -= &modulus;
+            }
+            k -= &d;
+            i8::try_from(d).expect("wNAF digit fits in i8")
+        } else {
+            0
+        };
+        digits.push(digit);
+        k >>= 1;
+    }
+
+    digits
+}
+
+/// wNAFによるスカラー倍算を定義
+impl Point {
+    /// 幅 `window` のwNAFによるスカラー倍算
+    ///
+    /// 奇数倍 P, 3P, …, (2^(w-1) - 1)P を事前計算し、
+    /// 0でない桁のみ加算する。桁の並びに応じて分岐するため可変時間であり、
+    /// 公開スカラーにのみ使用すること。
+    pub fn mul_wnaf(&self, scalar: impl Into<BigInt>, window: usize) -> Result<Self, PointError> {
+        if !(2..=8).contains(&window) {
+            return Err(PointError::InvalidWindowSize);
+        }
+
+        let scalar = scalar.into();
+        let digits = wnaf_digits(scalar.magnitude(), window);
+
+        // 奇数倍の表: table[i] = (2i + 1)P
+        let base = JacobianPoint::from(self);
+        let twice = base.double();
+        let mut table = vec![base];
+        for i in 1..(1 << (window - 2)) {
+            let next = (&table[i - 1] + &twice)?;
+            table.push(next);
+        }
+
+        let mut result = JacobianPoint::infinity(self.a.clone(), self.b.clone());
+        for &digit in digits.iter().rev() {
+            result = result.double();
+            if digit > 0 {
+                result = (&result + &table[(digit as usize - 1) / 2])?;
+            } else if digit < 0 {
+                let negated = -table[((-digit) as usize - 1) / 2].clone();
+                result = (&result + &negated)?;
+            }
+        }
+
+        let result = result.to_affine()?;
+        Ok(if scalar.is_negative() {
+            -result
+        } else {
+            result
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curve::Curve;
+
+    #[test]
+    fn test_wnaf_digits() {
+        // 7 = 8 - 1 → w = 2: [-1, 0, 0, 1]
+        assert_eq!(wnaf_digits(&BigUint::from(7u32), 2), vec![-1, 0, 0, 1]);
+
+        for window in 2..=6 {
+            for k in 0u32..500 {
+                let digits = wnaf_digits(&BigUint::from(k), window);
+                let value: i64 = digits
+                    .iter()
+                    .enumerate()
+                    .map(|(i, &d)| (d as i64) << i)
+                    .sum();
+                assert_eq!(value, k as i64);
+                assert!(digits.iter().all(|&d| d == 0 || d % 2 != 0));
+                assert!(digits.iter().all(|&d| (d as i64).abs() < 1 << (window - 1)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_mul_wnaf_matches_ladder() {
+        let curve = Curve::new(0, 7, 223).unwrap();
+        let p = curve.point(192, 105).unwrap();
+        for window in 2..=5 {
+            for k in -50..50 {
+                assert_eq!(
+                    p.mul_wnaf(k, window).unwrap(),
+                    (p.clone() * k).unwrap(),
+                    "window = {window}, k = {k}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid_window() {
+        let curve = Curve::new(0, 7, 223).unwrap();
+        let p = curve.point(192, 105).unwrap();
+        assert!(matches!(
+            p.mul_wnaf(5, 1),
+            Err(PointError::InvalidWindowSize)
+        ));
+        assert!(matches!(
+            p.mul_wnaf(5, 9),
+            Err(PointError::InvalidWindowSize)
+        ));
+    }
+}
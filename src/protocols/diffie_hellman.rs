@@ -1,44 +1,103 @@
-use super::{generate_private_key, ProtocolError};
+use super::{generate_private_key, generator_order, prime_generator_order, ProtocolError};
 use crate::curve::Curve;
-use crate::point::Point;
-
-pub struct DiffieHellman {
-    private_key: i64,
-    public_key: Point,
+use crate::point::{CurvePoint, FixedBaseTable, Point};
+use num_bigint::{BigInt, BigUint};
+
+/// Diffie-Hellman鍵交換
+///
+/// 点の型 `P` は `CurvePoint` を実装していればよく、Weierstrass形式でもEdwards形式でも使える。
+pub struct DiffieHellman<P = Point> {
+    order: BigUint,
+    private_key: BigUint,
+    public_key: P,
 }
 
-/// Diffie-Hellman鍵交換の実装
+/// 短縮Weierstrass形式の曲線からインスタンスを作成
 impl DiffieHellman {
     /// 新しいDiffie-Hellmanインスタンスを作成
+    ///
+    /// 公開鍵は1回のMontgomeryラダーで計算する。同じ生成点で多数の鍵を生成する場合は
+    /// `with_table` で事前計算テーブルを共有する。
     pub fn new(curve: Curve, generator: Point) -> Result<Self, ProtocolError> {
-        let order = curve
-            .point_order(&generator)
-            .map_err(|_| ProtocolError::InvalidParameters)?;
+        let order = generator_order(&curve, &generator)?;
+        Self::generate(&generator, order)
+    }
 
-        let private_key = generate_private_key(order);
-        let public_key =
-            (generator.clone() * private_key).map_err(|_| ProtocolError::OperationFailed)?;
+    /// 位数が素数の生成点に限ってインスタンスを作成
+    ///
+    /// 生成点の位数が合成数の場合は `InvalidParameters` を返す。
+    pub fn new_prime_order(curve: Curve, generator: Point) -> Result<Self, ProtocolError> {
+        let order = prime_generator_order(&curve, &generator)?;
+        Self::generate(&generator, order)
+    }
 
+    /// 生成点の事前計算テーブルを共有してインスタンスを作成
+    ///
+    /// テーブルは呼び出し側で一度だけ構築し、多数の鍵生成で使い回す。
+    pub fn with_table(curve: &Curve, table: &FixedBaseTable) -> Result<Self, ProtocolError> {
+        let order = generator_order(curve, table.base())?;
+        let private_key = generate_private_key(&order);
+        let public_key = table
+            .mul(&BigInt::from(private_key.clone()))
+            .map_err(|_| ProtocolError::OperationFailed)?;
         Ok(Self {
+            order,
             private_key,
             public_key,
         })
     }
+}
+
+/// Diffie-Hellman鍵交換の実装
+impl<P: CurvePoint> DiffieHellman<P> {
+    /// 位数が分かっている生成点からインスタンスを作成
+    ///
+    /// 位数 n が2以上で n·G = O を満たすことを確認する。
+    pub fn from_generator(generator: &P, order: &BigUint) -> Result<Self, ProtocolError> {
+        let annihilates = generator
+            .mul_public(order)
+            .is_ok_and(|point| point.is_identity());
+        if *order <= BigUint::from(1u32) || !annihilates {
+            return Err(ProtocolError::InvalidParameters);
+        }
+        Self::generate(generator, order.clone())
+    }
+
+    /// 位数を確認済みの生成点からMontgomeryラダーで鍵を生成
+    fn generate(generator: &P, order: BigUint) -> Result<Self, ProtocolError> {
+        let private_key = generate_private_key(&order);
+        let public_key = generator
+            .mul_secret(&private_key)
+            .map_err(|_| ProtocolError::OperationFailed)?;
+        Ok(Self {
+            order,
+            private_key,
+            public_key,
+        })
+    }
+
+    /// 生成点の位数を取得
+    pub fn order(&self) -> &BigUint {
+        &self.order
+    }
 
     /// 公開鍵を取得
-    pub fn public_key(&self) -> &Point {
+    pub fn public_key(&self) -> &P {
         &self.public_key
     }
 
     /// 共有鍵を計算
-    pub fn compute_shared_secret(&self, other_public: &Point) -> Result<Point, ProtocolError> {
-        (other_public.clone() * self.private_key).map_err(|_| ProtocolError::OperationFailed)
+    pub fn compute_shared_secret(&self, other_public: &P) -> Result<P, ProtocolError> {
+        other_public
+            .mul_secret(&self.private_key)
+            .map_err(|_| ProtocolError::OperationFailed)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::protocols::ed25519;
 
     #[test]
     fn test_diffie_hellman_key_exchange() {
@@ -66,12 +125,14 @@ mod tests {
 
         // 手動で異なる秘密鍵を設定してテスト
         let dh1 = DiffieHellman {
-            private_key: 7, // 明示的に異なる値を使用
+            order: BigUint::from(7u32),
+            private_key: BigUint::from(7u32), // 明示的に異なる値を使用
             public_key: (generator.clone() * 7).unwrap(),
         };
 
         let dh2 = DiffieHellman {
-            private_key: 13, // 明示的に異なる値を使用
+            order: BigUint::from(7u32),
+            private_key: BigUint::from(13u32), // 明示的に異なる値を使用
             public_key: (generator.clone() * 13).unwrap(),
         };
 
@@ -86,4 +147,76 @@ mod tests {
         assert_eq!(shared1.x, shared2.x);
         assert_eq!(shared1.y, shared2.y);
     }
+
+    #[test]
+    fn test_shared_generator_table() {
+        let curve = Curve::new(0, 7, 223).unwrap();
+        let generator = curve.point(15, 86).unwrap();
+        let table = FixedBaseTable::new(&generator, &BigUint::from(7u32)).unwrap();
+
+        let alice = DiffieHellman::with_table(&curve, &table).unwrap();
+        let bob = DiffieHellman::with_table(&curve, &table).unwrap();
+
+        // 公開鍵はテーブルを使わない計算と一致する
+        assert_eq!(
+            alice.public_key(),
+            &(generator.clone() * &alice.private_key).unwrap()
+        );
+
+        let alice_shared = alice.compute_shared_secret(bob.public_key()).unwrap();
+        let bob_shared = bob.compute_shared_secret(alice.public_key()).unwrap();
+        assert_eq!(alice_shared, bob_shared);
+    }
+
+    #[test]
+    fn test_generator_order_is_memoized() {
+        let curve = Curve::new(0, 7, 223).unwrap();
+        let generator = curve.point(15, 86).unwrap();
+        let dh = DiffieHellman::new(curve, generator).unwrap();
+        assert_eq!(dh.order(), &BigUint::from(7u32));
+        assert!(dh.private_key < BigUint::from(7u32));
+    }
+
+    #[test]
+    fn test_new_prime_order() {
+        let curve = Curve::new(0, 7, 223).unwrap();
+        let generator = curve.find_generator(&BigUint::from(7u32)).unwrap();
+        let dh = DiffieHellman::new_prime_order(curve.clone(), generator).unwrap();
+        assert_eq!(dh.order(), &BigUint::from(7u32));
+
+        // 位数42の点は拒否する
+        let composite = curve.point(192, 105).unwrap();
+        assert!(matches!(
+            DiffieHellman::new_prime_order(curve, composite),
+            Err(ProtocolError::InvalidParameters)
+        ));
+    }
+
+    #[test]
+    fn test_edwards_key_exchange() {
+        let generator = ed25519::base_point();
+        let order = ed25519::group_order();
+        let alice = DiffieHellman::from_generator(&generator, &order).unwrap();
+        let bob = DiffieHellman::from_generator(&generator, &order).unwrap();
+
+        let alice_shared = alice.compute_shared_secret(bob.public_key()).unwrap();
+        let bob_shared = bob.compute_shared_secret(alice.public_key()).unwrap();
+        assert_eq!(alice_shared, bob_shared);
+
+        // 位数でない値は拒否する
+        assert!(matches!(
+            DiffieHellman::from_generator(&generator, &(order - 1u32)),
+            Err(ProtocolError::InvalidParameters)
+        ));
+    }
+
+    #[test]
+    fn test_rejects_infinity_generator() {
+        let curve = Curve::new(0, 7, 223).unwrap();
+        let infinity = curve.infinity_point();
+        assert!(matches!(
+            DiffieHellman::new(curve, infinity),
+            Err(ProtocolError::InvalidParameters)
+        ));
+    }
 }
@@ -0,0 +1,596 @@
+use super::nonce::{NonceSource, RandomNonce, Rfc6979};
+use super::{bits_to_int, generate_private_key, prime_generator_order, ProtocolError};
+use crate::arith::is_probable_prime;
+use crate::curve::{Curve, CurveParams};
+use crate::point::Point;
+use num_bigint::{BigInt, BigUint};
+use num_traits::Zero;
+use sha2::Sha256;
+
+/// ECDSA署名 (r, s)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Signature {
+    pub r: BigUint,
+    pub s: BigUint,
+}
+
+/// ECDSAの署名鍵
+pub struct SigningKey {
+    order: BigUint,
+    private_key: BigUint,
+    verifying_key: VerifyingKey,
+}
+
+/// ECDSAの検証鍵
+#[derive(Debug, Clone)]
+pub struct VerifyingKey {
+    generator: Point,
+    order: BigUint,
+    public_key: Point,
+}
+
+/// 署名鍵の生成と署名を定義
+impl SigningKey {
+    /// 新しい署名鍵をランダムに生成
+    ///
+    /// 生成点の位数を計算するので、小さな曲線向け。位数は素数でなければならない。
+    pub fn new(curve: Curve, generator: Point) -> Result<Self, ProtocolError> {
+        let order = prime_generator_order(&curve, &generator)?;
+        let private_key = generate_private_key(&order);
+        Self::from_private_key(&generator, &order, private_key)
+    }
+
+    /// 標準曲線のパラメータから署名鍵をランダムに生成
+    pub fn from_params(params: &CurveParams) -> Result<Self, ProtocolError> {
+        let private_key = generate_private_key(&params.order);
+        Self::from_private_key(&params.generator, &params.order, private_key)
+    }
+
+    /// 秘密鍵 d ∈ [1, n) から署名鍵を作成
+    pub fn from_private_key(
+        generator: &Point,
+        order: &BigUint,
+        private_key: BigUint,
+    ) -> Result<Self, ProtocolError> {
+        if private_key.is_zero() || private_key >= *order {
+            return Err(ProtocolError::InvalidParameters);
+        }
+
+        // 秘密鍵のスカラー倍算はMontgomeryラダーで行う
+        let public_key =
+            (generator.clone() * &private_key).map_err(|_| ProtocolError::OperationFailed)?;
+        let verifying_key = VerifyingKey::new(generator, order, public_key)?;
+
+        Ok(Self {
+            order: order.clone(),
+            private_key,
+            verifying_key,
+        })
+    }
+
+    /// 検証鍵を取得
+    pub fn verifying_key(&self) -> &VerifyingKey {
+        &self.verifying_key
+    }
+
+    /// メッセージのハッシュ値に署名
+    ///
+    /// ナンス k はランダムに選び、s は low-s に正規化する。
+    pub fn sign(&self, msg_hash: &[u8]) -> Result<Signature, ProtocolError> {
+        self.sign_with(msg_hash, &RandomNonce)
+    }
+
+    /// RFC 6979 (HMAC-SHA-256) の決定的なナンスで署名
+    pub fn sign_deterministic(&self, msg_hash: &[u8]) -> Result<Signature, ProtocolError> {
+        self.sign_with(msg_hash, &Rfc6979::<Sha256>::new())
+    }
+
+    /// 指定したナンスの生成方法で署名
+    pub fn sign_with(
+        &self,
+        msg_hash: &[u8],
+        source: &dyn NonceSource,
+    ) -> Result<Signature, ProtocolError> {
+        for nonce in source.nonces(&self.private_key, msg_hash, &self.order) {
+            // r = 0 または s = 0 になった場合は次の候補を使う
+            if let Ok(signature) = self.sign_with_nonce(msg_hash, &nonce) {
+                return Ok(signature);
+            }
+        }
+        Err(ProtocolError::OperationFailed)
+    }
+
+    /// 与えられたナンス k で署名
+    ///
+    /// r = (kG).x mod n, s = k⁻¹(e + rd) mod n を計算し、low-s に正規化する。
+    /// 同じ k を異なるメッセージに使うと秘密鍵が漏れるので、テストベクタの再現以外には使わないこと。
+    pub fn sign_with_nonce(
+        &self,
+        msg_hash: &[u8],
+        nonce: &BigUint,
+    ) -> Result<Signature, ProtocolError> {
+        let n = &self.order;
+        if nonce.is_zero() || nonce >= n {
+            return Err(ProtocolError::InvalidParameters);
+        }
+
+        let point = (self.verifying_key.generator.clone() * nonce)
+            .map_err(|_| ProtocolError::OperationFailed)?;
+        let r = point
+            .x
+            .as_ref()
+            .ok_or(ProtocolError::OperationFailed)?
+            .value()
+            % n;
+        if r.is_zero() {
+            return Err(ProtocolError::OperationFailed);
+        }
+
+        let e = bits_to_int(msg_hash, n);
+        let s = inv_mod(nonce, n) * ((e + &r * &self.private_key) % n) % n;
+        if s.is_zero() {
+            return Err(ProtocolError::OperationFailed);
+        }
+
+        Ok(Signature { r, s }.normalize_s(n))
+    }
+}
+
+/// 検証鍵の生成と検証を定義
+impl VerifyingKey {
+    /// 公開鍵 Q から検証鍵を作成
+    ///
+    /// Q が生成点と同じ曲線上にあり、無限遠点ではなく、n·Q = O を満たすことを確認する。
+    pub fn new(
+        generator: &Point,
+        order: &BigUint,
+        public_key: Point,
+    ) -> Result<Self, ProtocolError> {
+        if !is_probable_prime(order)
+            || !generator.is_in_prime_order_subgroup(order)
+            || public_key.is_infinity()
+            || public_key.a != generator.a
+            || public_key.b != generator.b
+            || !public_key.is_in_prime_order_subgroup(order)
+        {
+            return Err(ProtocolError::InvalidParameters);
+        }
+        Ok(Self {
+            generator: generator.clone(),
+            order: order.clone(),
+            public_key,
+        })
+    }
+
+    /// 公開鍵を取得
+    pub fn public_key(&self) -> &Point {
+        &self.public_key
+    }
+
+    /// 署名を検証
+    ///
+    /// u₁ = e·s⁻¹, u₂ = r·s⁻¹ として X = u₁G + u₂Q を計算し、X.x ≡ r (mod n) を確認する。
+    /// high-s の署名も受け付けるので、拒否したい場合は `Signature::is_low_s` を併用する。
+    pub fn verify(&self, msg_hash: &[u8], signature: &Signature) -> bool {
+        let n = &self.order;
+        let Signature { r, s } = signature;
+        if r.is_zero() || r >= n || s.is_zero() || s >= n {
+            return false;
+        }
+
+        let e = bits_to_int(msg_hash, n);
+        let w = inv_mod(s, n);
+        let u1 = e * &w % n;
+        let u2 = r * &w % n;
+
+        let terms = [
+            (self.generator.clone(), BigInt::from(u1)),
+            (self.public_key.clone(), BigInt::from(u2)),
+        ];
+        match Point::multi_scalar_mul(&terms) {
+            Ok(point) => point.x.is_some_and(|x| x.value() % n == *r),
+            Err(_) => false,
+        }
+    }
+}
+
+/// 署名の正規化とエンコードを定義
+impl Signature {
+    /// s > n/2 なら n - s に置き換える
+    ///
+    /// (r, s) と (r, n - s) はどちらも有効な署名なので、low-s に揃えて展性を防ぐ。
+    pub fn normalize_s(self, order: &BigUint) -> Self {
+        if self.is_low_s(order) {
+            self
+        } else {
+            Self {
+                s: order - &self.s,
+                r: self.r,
+            }
+        }
+    }
+
+    /// s ≤ n/2 かどうかを判定
+    pub fn is_low_s(&self, order: &BigUint) -> bool {
+        self.s <= order >> 1u32
+    }
+
+    /// 位数のバイト長に揃えた r || s にエンコード
+    ///
+    /// r, s が [1, n) の範囲外なら `InvalidEncoding` を返す。
+    pub fn to_bytes(&self, order: &BigUint) -> Result<Vec<u8>, ProtocolError> {
+        self.check_range(order)?;
+        let len = scalar_len(order);
+        let mut bytes = Vec::with_capacity(2 * len);
+        for value in [&self.r, &self.s] {
+            let encoded = value.to_bytes_be();
+            bytes.resize(bytes.len() + len - encoded.len(), 0);
+            bytes.extend(encoded);
+        }
+        Ok(bytes)
+    }
+
+    /// r || s 形式からデコード
+    pub fn from_bytes(bytes: &[u8], order: &BigUint) -> Result<Self, ProtocolError> {
+        let len = scalar_len(order);
+        if bytes.len() != 2 * len {
+            return Err(ProtocolError::InvalidEncoding);
+        }
+        let signature = Self {
+            r: BigUint::from_bytes_be(&bytes[..len]),
+            s: BigUint::from_bytes_be(&bytes[len..]),
+        };
+        signature.check_range(order)?;
+        Ok(signature)
+    }
+
+    /// DER形式 (SEQUENCE { INTEGER r, INTEGER s }) にエンコード
+    pub fn to_der(&self) -> Vec<u8> {
+        let mut body = Vec::new();
+        for value in [&self.r, &self.s] {
+            let mut encoded = value.to_bytes_be();
+            // 最上位ビットが立っている場合は負数と区別するため 0x00 を前置する
+            if encoded[0] & 0x80 != 0 {
+                encoded.insert(0, 0x00);
+            }
+            body.push(0x02);
+            push_der_length(&mut body, encoded.len());
+            body.extend(encoded);
+        }
+
+        let mut der = vec![0x30];
+        push_der_length(&mut der, body.len());
+        der.extend(body);
+        der
+    }
+
+    /// DER形式からデコード
+    ///
+    /// r, s が [1, n) の範囲外なら `InvalidEncoding` を返す。
+    pub fn from_der(bytes: &[u8], order: &BigUint) -> Result<Self, ProtocolError> {
+        let (tag, body, rest) = read_der(bytes)?;
+        if tag != 0x30 || !rest.is_empty() {
+            return Err(ProtocolError::InvalidEncoding);
+        }
+        let (r_tag, r, body) = read_der(body)?;
+        let (s_tag, s, body) = read_der(body)?;
+        if r_tag != 0x02 || s_tag != 0x02 || !body.is_empty() {
+            return Err(ProtocolError::InvalidEncoding);
+        }
+        let signature = Self {
+            r: read_der_integer(r)?,
+            s: read_der_integer(s)?,
+        };
+        signature.check_range(order)?;
+        Ok(signature)
+    }
+
+    /// r, s ∈ [1, n) を確認
+    fn check_range(&self, order: &BigUint) -> Result<(), ProtocolError> {
+        if [&self.r, &self.s]
+            .into_iter()
+            .any(|value| value.is_zero() || value >= order)
+        {
+            return Err(ProtocolError::InvalidEncoding);
+        }
+        Ok(())
+    }
+}
+
+/// 素数 n を法とする逆元 k⁻¹ = k^(n-2) mod n
+fn inv_mod(k: &BigUint, n: &BigUint) -> BigUint {
+    k.modpow(&(n - 2u32), n)
+}
+
+/// スカラーのバイト長
+fn scalar_len(order: &BigUint) -> usize {
+    order.bits().div_ceil(8) as usize
+}
+
+/// DERの長さを書き込む
+fn push_der_length(out: &mut Vec<u8>, len: usize) {
+    if len < 0x80 {
+        out.push(len as u8);
+    } else {
+        let bytes: Vec<u8> = len
+            .to_be_bytes()
+            .into_iter()
+            .skip_while(|&b| b == 0)
+            .collect();
+        out.push(0x80 | bytes.len() as u8);
+        out.extend(bytes);
+    }
+}
+
+/// DERの要素を1つ読み、(タグ, 内容, 残り) を返す
+fn read_der(bytes: &[u8]) -> Result<(u8, &[u8], &[u8]), ProtocolError> {
+    let (&tag, rest) = bytes.split_first().ok_or(ProtocolError::InvalidEncoding)?;
+    let (&first, rest) = rest.split_first().ok_or(ProtocolError::InvalidEncoding)?;
+
+    let (len, rest) = if first < 0x80 {
+        (first as usize, rest)
+    } else {
+        // 長形式は必要最小限のバイト数でなければならない
+        let count = (first & 0x7F) as usize;
+        if count == 0 || count > std::mem::size_of::<usize>() || rest.len() < count {
+            return Err(ProtocolError::InvalidEncoding);
+        }
+        let (len_bytes, rest) = rest.split_at(count);
+        let len = len_bytes
+            .iter()
+            .fold(0usize, |acc, &b| (acc << 8) | b as usize);
+        if len < 0x80 || len_bytes[0] == 0 {
+            return Err(ProtocolError::InvalidEncoding);
+        }
+        (len, rest)
+    };
+
+    if rest.len() < len {
+        return Err(ProtocolError::InvalidEncoding);
+    }
+    let (content, rest) = rest.split_at(len);
+    Ok((tag, content, rest))
+}
+
+/// DERの正の整数を読む
+fn read_der_integer(content: &[u8]) -> Result<BigUint, ProtocolError> {
+    match content {
+        // 空、負数、不要な 0x00 の前置は拒否する
+        [] => Err(ProtocolError::InvalidEncoding),
+        [first, ..] if first & 0x80 != 0 => Err(ProtocolError::InvalidEncoding),
+        [0x00, second, ..] if second & 0x80 == 0 => Err(ProtocolError::InvalidEncoding),
+        _ => Ok(BigUint::from_bytes_be(content)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocols::{hex_bytes, hex_uint};
+    use sha2::Digest;
+
+    #[test]
+    fn test_sign_and_verify_toy_curve() {
+        // y² = x³ + 7 over F223, (15, 86) の位数は7
+        let curve = Curve::new(0, 7, 223).unwrap();
+        let generator = curve.point(15, 86).unwrap();
+        let key = SigningKey::new(curve, generator).unwrap();
+
+        let msg_hash = [0x05];
+        let signature = key.sign(&msg_hash).unwrap();
+        assert!(key.verifying_key().verify(&msg_hash, &signature));
+        assert!(signature.is_low_s(&key.order));
+    }
+
+    #[test]
+    fn test_rejects_composite_order_generator() {
+        let curve = Curve::new(0, 7, 223).unwrap();
+        let generator = curve.point(192, 105).unwrap();
+        assert!(matches!(
+            SigningKey::new(curve, generator),
+            Err(ProtocolError::InvalidParameters)
+        ));
+    }
+
+    #[test]
+    fn test_rfc6979_p256_vector() {
+        // RFC 6979 A.2.5, SHA-256, message "sample"
+        let params = Curve::p256();
+        let key = SigningKey::from_private_key(
+            &params.generator,
+            &params.order,
+            hex_uint("C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721"),
+        )
+        .unwrap();
+        let public_key = key.verifying_key().public_key();
+        assert_eq!(
+            public_key.x.as_ref().unwrap().value(),
+            &hex_uint("60FED4BA255A9D31C961EB74C6356D68C049B8923B61FA6CE669622E60F29FB6")
+        );
+
+        let msg_hash =
+            hex_bytes("AF2BDBE1AA9B6EC1E2ADE1D694F41FC71A831D0268E9891562113D8A62ADD1BF");
+        let nonce = hex_uint("A6E3C57DD01ABE90086538398355DD4C3B17AA873382B0F24D6129493D8AAD60");
+        let expected = Signature {
+            r: hex_uint("EFD48B2AACB6A8FD1140DD9CD45E81D69D2C877B56AAF991C34D0EA84EAF3716"),
+            s: hex_uint("F7CB1C942D657C41D436C7A1B6E29F65F3E900DBB9AFF4064DC4AB2F843ACDA8"),
+        };
+
+        // RFC の s は high-s なので、正規化した値が返る
+        let signature = key.sign_with_nonce(&msg_hash, &nonce).unwrap();
+        assert_eq!(signature, expected.clone().normalize_s(&params.order));
+        assert_eq!(signature.s, &params.order - &expected.s);
+
+        // 検証はどちらの s も受け付ける
+        let verifying_key = key.verifying_key();
+        assert!(verifying_key.verify(&msg_hash, &signature));
+        assert!(verifying_key.verify(&msg_hash, &expected));
+        assert!(!expected.is_low_s(&params.order));
+    }
+
+    #[test]
+    fn test_rfc6979_deterministic_signatures() {
+        // RFC 6979 A.2.5, SHA-256
+        let params = Curve::p256();
+        let key = SigningKey::from_private_key(
+            &params.generator,
+            &params.order,
+            hex_uint("C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721"),
+        )
+        .unwrap();
+
+        for (message, r, s) in [
+            (
+                "sample",
+                "EFD48B2AACB6A8FD1140DD9CD45E81D69D2C877B56AAF991C34D0EA84EAF3716",
+                "F7CB1C942D657C41D436C7A1B6E29F65F3E900DBB9AFF4064DC4AB2F843ACDA8",
+            ),
+            (
+                "test",
+                "F1ABB023518351CD71D881567B1EA663ED3EFCF6C5132B354F28D3B0B7D38367",
+                "019F4113742A2B14BD25926B49C649155F267E60D3814B4C0CC84250E46F0083",
+            ),
+        ] {
+            let msg_hash = Sha256::digest(message.as_bytes());
+            let expected = Signature {
+                r: hex_uint(r),
+                s: hex_uint(s),
+            }
+            .normalize_s(&params.order);
+            let signature = key.sign_deterministic(&msg_hash).unwrap();
+            assert_eq!(signature, expected, "{message}");
+            assert_eq!(key.sign_deterministic(&msg_hash).unwrap(), signature);
+            assert!(key.verifying_key().verify(&msg_hash, &signature));
+        }
+    }
+
+    #[test]
+    fn test_sign_with_nonce_source() {
+        let curve = Curve::new(0, 7, 223).unwrap();
+        let generator = curve.point(15, 86).unwrap();
+        let key = SigningKey::new(curve, generator).unwrap();
+        let signature = key.sign_with(&[0x05], &RandomNonce).unwrap();
+        assert!(key.verifying_key().verify(&[0x05], &signature));
+        let deterministic = key.sign_deterministic(&[0x05]).unwrap();
+        assert_eq!(deterministic, key.sign_deterministic(&[0x05]).unwrap());
+    }
+
+    #[test]
+    fn test_verify_rejects_tampering() {
+        let params = Curve::secp256k1();
+        let key = SigningKey::from_params(&params).unwrap();
+        let other = SigningKey::from_params(&params).unwrap();
+        let msg_hash = [0x42u8; 32];
+        let signature = key.sign(&msg_hash).unwrap();
+        let verifying_key = key.verifying_key();
+
+        assert!(verifying_key.verify(&msg_hash, &signature));
+        assert!(!verifying_key.verify(&[0x43u8; 32], &signature));
+        assert!(!other.verifying_key().verify(&msg_hash, &signature));
+
+        let tampered = Signature {
+            r: signature.r.clone(),
+            s: &signature.s + 1u32,
+        };
+        assert!(!verifying_key.verify(&msg_hash, &tampered));
+        let out_of_range = Signature {
+            r: params.order.clone(),
+            s: signature.s.clone(),
+        };
+        assert!(!verifying_key.verify(&msg_hash, &out_of_range));
+    }
+
+    #[test]
+    fn test_verifying_key_validation() {
+        let params = Curve::secp256k1();
+        let infinity = params.curve.infinity_point();
+        assert!(VerifyingKey::new(&params.generator, &params.order, infinity).is_err());
+
+        // 別の曲線の点は拒否する
+        let p256 = Curve::p256();
+        assert!(
+            VerifyingKey::new(&params.generator, &params.order, p256.generator.clone()).is_err()
+        );
+        assert!(
+            VerifyingKey::new(&params.generator, &params.order, params.generator.clone()).is_ok()
+        );
+    }
+
+    #[test]
+    fn test_signature_encoding() {
+        let params = Curve::p256();
+        let signature = Signature {
+            r: hex_uint("EFD48B2AACB6A8FD1140DD9CD45E81D69D2C877B56AAF991C34D0EA84EAF3716"),
+            s: BigUint::from(0x1234u32),
+        };
+
+        let bytes = signature.to_bytes(&params.order).unwrap();
+        assert_eq!(bytes.len(), 64);
+        assert_eq!(&bytes[60..], &[0, 0, 0x12, 0x34]);
+        assert_eq!(
+            Signature::from_bytes(&bytes, &params.order).unwrap(),
+            signature
+        );
+        assert!(Signature::from_bytes(&bytes[1..], &params.order).is_err());
+
+        // r は最上位ビットが立つので 0x00 が前置される
+        let der = signature.to_der();
+        assert_eq!(&der[..5], &[0x30, 39, 0x02, 33, 0x00]);
+        assert_eq!(&der[37..], &[0x02, 2, 0x12, 0x34]);
+        let n = &params.order;
+        assert_eq!(Signature::from_der(&der, n).unwrap(), signature);
+
+        // 不正なDER
+        let mut trailing = der.clone();
+        trailing.push(0);
+        assert!(Signature::from_der(&trailing, n).is_err());
+        assert!(Signature::from_der(&der[..der.len() - 1], n).is_err());
+        assert!(Signature::from_der(&[0x30, 6, 0x02, 1, 0x80, 0x02, 1, 0x01], n).is_err());
+        assert!(Signature::from_der(&[0x30, 7, 0x02, 2, 0x00, 0x01, 0x02, 1, 0x01], n).is_err());
+    }
+
+    #[test]
+    fn test_rejects_out_of_range_scalars() {
+        let params = Curve::p256();
+        let n = &params.order;
+
+        // 位数より長い33バイトのDER整数
+        let mut der = vec![0x30, 38, 0x02, 33, 0x01];
+        der.extend([0xFF; 32]);
+        der.extend([0x02, 1, 0x01]);
+        assert!(matches!(
+            Signature::from_der(&der, n),
+            Err(ProtocolError::InvalidEncoding)
+        ));
+
+        for (r, s) in [
+            (BigUint::zero(), BigUint::from(1u32)),
+            (BigUint::from(1u32), n.clone()),
+            (BigUint::from(1u32), BigUint::from(1u32) << 300u32),
+        ] {
+            let signature = Signature { r, s };
+            assert!(matches!(
+                signature.to_bytes(n),
+                Err(ProtocolError::InvalidEncoding)
+            ));
+            assert!(Signature::from_der(&signature.to_der(), n).is_err());
+        }
+
+        let mut bytes = vec![0xFF; 32];
+        bytes.extend([0x01; 32]);
+        assert!(Signature::from_bytes(&bytes, n).is_err());
+    }
+
+    #[test]
+    fn test_der_long_form_length() {
+        // P-521 の署名は 128 バイトを超える
+        let params = Curve::p521();
+        let signature = Signature {
+            r: &params.order - 1u32,
+            s: &params.order - 2u32,
+        };
+        let der = signature.to_der();
+        assert_eq!(&der[..2], &[0x30, 0x81]);
+        assert_eq!(Signature::from_der(&der, &params.order).unwrap(), signature);
+    }
+}
@@ -0,0 +1,283 @@
+//! Ed25519 署名 (RFC 8032)
+
+use super::{clamp_scalar_25519, ProtocolError};
+use crate::curve::{EdwardsCurve, EdwardsPoint};
+use crate::point::CurvePoint;
+use num_bigint::{BigInt, BigUint};
+use rand::Rng;
+use sha2::{Digest, Sha512};
+
+/// Ed25519 署名 (R のエンコードと S)
+///
+/// 署名か `from_bytes` でのみ生成するので、S は常に 2²⁵⁶ 未満に収まる。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Signature {
+    r: [u8; 32],
+    s: BigUint,
+}
+
+/// Ed25519 の署名鍵
+pub struct SigningKey {
+    scalar: BigUint,
+    prefix: [u8; 32],
+    verifying_key: VerifyingKey,
+}
+
+/// Ed25519 の検証鍵
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerifyingKey {
+    public_key: EdwardsPoint,
+    encoded: [u8; 32],
+}
+
+/// 基点 B = (x, 4/5) (x は偶数)
+pub fn base_point() -> EdwardsPoint {
+    let mut encoded = [0x66u8; 32];
+    encoded[0] = 0x58;
+    EdwardsCurve::ed25519()
+        .decode_point(&encoded)
+        .expect("base point encoding is valid")
+}
+
+/// 基点の位数 L = 2²⁵² + 27742317777372353535851937790883648493
+pub fn group_order() -> BigUint {
+    (BigUint::from(1u32) << 252u32)
+        + BigUint::parse_bytes(b"27742317777372353535851937790883648493", 10)
+            .expect("constant is valid")
+}
+
+/// 署名鍵の生成と署名を定義
+impl SigningKey {
+    /// 新しい署名鍵をランダムに生成
+    pub fn new() -> Result<Self, ProtocolError> {
+        let mut secret = [0u8; 32];
+        rand::rng().fill(&mut secret);
+        Self::from_bytes(&secret)
+    }
+
+    /// 32バイトの秘密鍵から署名鍵を作成
+    ///
+    /// SHA-512(秘密鍵) の前半をクランプしてスカラー a とし、後半をナンス導出用の prefix とする。
+    pub fn from_bytes(secret: &[u8]) -> Result<Self, ProtocolError> {
+        if secret.len() != 32 {
+            return Err(ProtocolError::InvalidEncoding);
+        }
+        let hash = Sha512::digest(secret);
+        let scalar = clamp_scalar_25519(hash[..32].try_into().expect("slice is 32 bytes"));
+        let prefix: [u8; 32] = hash[32..].try_into().expect("SHA-512 output is 64 bytes");
+
+        let public_key = base_point()
+            .mul_secret(&scalar)
+            .map_err(|_| ProtocolError::OperationFailed)?;
+        let verifying_key = VerifyingKey::from_point(public_key);
+
+        Ok(Self {
+            scalar,
+            prefix,
+            verifying_key,
+        })
+    }
+
+    /// 検証鍵を取得
+    pub fn verifying_key(&self) -> &VerifyingKey {
+        &self.verifying_key
+    }
+
+    /// メッセージに署名
+    ///
+    /// r = SHA-512(prefix || M) mod L, R = rB, k = SHA-512(R || A || M) mod L,
+    /// S = r + k·a mod L を計算する。ナンスは決定的に導出されるので乱数は使わない。
+    pub fn sign(&self, msg: &[u8]) -> Result<Signature, ProtocolError> {
+        let order = group_order();
+        let nonce = hash_to_scalar(&[&self.prefix, msg], &order);
+        let r: [u8; 32] = base_point()
+            .mul_secret(&nonce)
+            .map_err(|_| ProtocolError::OperationFailed)?
+            .encode()
+            .try_into()
+            .map_err(|_| ProtocolError::OperationFailed)?;
+
+        let k = hash_to_scalar(&[&r, &self.verifying_key.encoded, msg], &order);
+        let s = (nonce + k * &self.scalar) % &order;
+        Ok(Signature { r, s })
+    }
+}
+
+/// 検証鍵のエンコードと検証を定義
+impl VerifyingKey {
+    /// 32バイトのエンコードから公開鍵を復元
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ProtocolError> {
+        let public_key = EdwardsCurve::ed25519()
+            .decode_point(bytes)
+            .map_err(|_| ProtocolError::InvalidEncoding)?;
+        Ok(Self::from_point(public_key))
+    }
+
+    fn from_point(public_key: EdwardsPoint) -> Self {
+        let encoded = public_key
+            .encode()
+            .try_into()
+            .expect("Ed25519 encoding is 32 bytes");
+        Self {
+            public_key,
+            encoded,
+        }
+    }
+
+    /// 32バイトにエンコード
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.encoded
+    }
+
+    /// 公開鍵の点 A を取得
+    pub fn public_key(&self) -> &EdwardsPoint {
+        &self.public_key
+    }
+
+    /// 署名を検証
+    ///
+    /// RFC 8032 5.1.7 に従い、S < L を確認した上で余因子付きの式 [8][S]B = [8]R + [8][k]A を確かめる。
+    pub fn verify(&self, msg: &[u8], signature: &Signature) -> bool {
+        let order = group_order();
+        let Signature { r, s } = signature;
+        if *s >= order {
+            return false;
+        }
+        let Ok(point_r) = EdwardsCurve::ed25519().decode_point(r) else {
+            return false;
+        };
+
+        let k = hash_to_scalar(&[r, &self.encoded, msg], &order);
+        let terms = [
+            (base_point(), BigInt::from(s.clone())),
+            (self.public_key.clone(), -BigInt::from(k)),
+            (point_r, BigInt::from(-1)),
+        ];
+        EdwardsPoint::linear_combination(&terms)
+            .and_then(|point| point.mul_public(&BigUint::from(8u32)))
+            .is_ok_and(|point| point.is_identity())
+    }
+}
+
+/// 署名のエンコードを定義
+impl Signature {
+    /// R のエンコードを取得
+    pub fn r(&self) -> &[u8; 32] {
+        &self.r
+    }
+
+    /// S を取得
+    pub fn s(&self) -> &BigUint {
+        &self.s
+    }
+
+    /// R || S (S はリトルエンディアン) の64バイトにエンコード
+    pub fn to_bytes(&self) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&self.r);
+        let s = self.s.to_bytes_le();
+        bytes[32..32 + s.len()].copy_from_slice(&s);
+        bytes
+    }
+
+    /// 64バイトの R || S からデコード
+    ///
+    /// S < L の確認は検証時に行う。
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ProtocolError> {
+        if bytes.len() != 64 {
+            return Err(ProtocolError::InvalidEncoding);
+        }
+        Ok(Self {
+            r: bytes[..32].try_into().expect("slice is 32 bytes"),
+            s: BigUint::from_bytes_le(&bytes[32..]),
+        })
+    }
+}
+
+/// SHA-512 の出力をリトルエンディアンの整数として L で簡約
+fn hash_to_scalar(data: &[&[u8]], order: &BigUint) -> BigUint {
+    let mut hasher = Sha512::new();
+    for part in data {
+        hasher.update(part);
+    }
+    BigUint::from_bytes_le(&hasher.finalize()) % order
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocols::hex_bytes;
+
+    #[test]
+    fn test_rfc8032_vectors() {
+        // RFC 8032 7.1 TEST 1-3
+        for (secret, public, msg, expected) in [
+            (
+                "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60",
+                "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+                "",
+                "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e06522490155\
+                 5fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b",
+            ),
+            (
+                "4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb",
+                "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+                "72",
+                "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da\
+                 085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00",
+            ),
+            (
+                "c5aa8df43f9f837bedb7442f31dcb7b166d38535076f094b85ce3a2e0b4458f7",
+                "fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025",
+                "af82",
+                "6291d657deec24024827e69c3abe01a30ce548a284743a445e3680d7db5ac3ac\
+                 18ff9b538d16f290ae67f760984dc6594a7c15e9716ed28dc027beceea1ec40a",
+            ),
+        ] {
+            let key = SigningKey::from_bytes(&hex_bytes(secret)).unwrap();
+            assert_eq!(key.verifying_key().to_bytes().to_vec(), hex_bytes(public));
+
+            let msg = hex_bytes(msg);
+            let signature = key.sign(&msg).unwrap();
+            assert_eq!(signature.to_bytes().to_vec(), hex_bytes(expected));
+
+            let verifying_key = VerifyingKey::from_bytes(&hex_bytes(public)).unwrap();
+            let decoded = Signature::from_bytes(&hex_bytes(expected)).unwrap();
+            assert!(verifying_key.verify(&msg, &decoded));
+        }
+    }
+
+    #[test]
+    fn test_verify_rejects_invalid_signatures() {
+        let key = SigningKey::new().unwrap();
+        let verifying_key = key.verifying_key();
+        let signature = key.sign(b"message").unwrap();
+        assert!(verifying_key.verify(b"message", &signature));
+        assert!(!verifying_key.verify(b"massage", &signature));
+        assert!(!SigningKey::new()
+            .unwrap()
+            .verifying_key()
+            .verify(b"message", &signature));
+
+        // S + L は同じ点を与えるが、展性を防ぐため拒否する
+        let malleable = Signature {
+            r: signature.r,
+            s: &signature.s + group_order(),
+        };
+        assert!(!verifying_key.verify(b"message", &malleable));
+
+        // R がデコードできない
+        let invalid_r = Signature {
+            r: [0xFF; 32],
+            s: signature.s.clone(),
+        };
+        assert!(!verifying_key.verify(b"message", &invalid_r));
+        assert!(Signature::from_bytes(&signature.to_bytes()[1..]).is_err());
+        assert!(SigningKey::from_bytes(&[0u8; 31]).is_err());
+
+        // S ≥ L もデコードでき、そのまま再エンコードできる
+        let max = Signature::from_bytes(&[0xFF; 64]).unwrap();
+        assert_eq!(max.to_bytes(), [0xFF; 64]);
+        assert!(!verifying_key.verify(b"message", &max));
+    }
+}
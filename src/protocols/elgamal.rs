@@ -1,11 +1,12 @@
-use super::{generate_private_key, ProtocolError};
+use super::{generate_private_key, generator_order, prime_generator_order, ProtocolError};
 use crate::curve::Curve;
-use crate::point::Point;
+use crate::point::{FixedBaseTable, Point};
+use num_bigint::{BigInt, BigUint};
 
 pub struct ElGamal {
-    curve: Curve,
-    generator: Point,
-    private_key: i64,
+    order: BigUint,
+    generator_table: FixedBaseTable,
+    private_key: BigUint,
     public_key: Point,
 }
 
@@ -19,17 +20,33 @@ pub struct Ciphertext {
 impl ElGamal {
     /// 新しいElGamalインスタンスを作成
     pub fn new(curve: Curve, generator: Point) -> Result<Self, ProtocolError> {
-        let order = curve
-            .point_order(&generator)
+        // 暗号化のたびに位数を計算し直さないよう保持する
+        let order = generator_order(&curve, &generator)?;
+        Self::with_order(generator, order)
+    }
+
+    /// 位数が素数の生成点に限ってインスタンスを作成
+    ///
+    /// 生成点の位数が合成数の場合は `InvalidParameters` を返す。
+    pub fn new_prime_order(curve: Curve, generator: Point) -> Result<Self, ProtocolError> {
+        let order = prime_generator_order(&curve, &generator)?;
+        Self::with_order(generator, order)
+    }
+
+    /// 位数を確認済みの生成点から鍵を生成
+    fn with_order(generator: Point, order: BigUint) -> Result<Self, ProtocolError> {
+        // 暗号化のたびに使う rG のために生成点のテーブルを保持する
+        let generator_table = FixedBaseTable::new(&generator, &order)
             .map_err(|_| ProtocolError::InvalidParameters)?;
 
-        let private_key = generate_private_key(order);
-        let public_key =
-            (generator.clone() * private_key).map_err(|_| ProtocolError::OperationFailed)?;
+        let private_key = generate_private_key(&order);
+        let public_key = generator_table
+            .mul(&BigInt::from(private_key.clone()))
+            .map_err(|_| ProtocolError::OperationFailed)?;
 
         Ok(Self {
-            curve,
-            generator,
+            order,
+            generator_table,
             private_key,
             public_key,
         })
@@ -39,21 +56,26 @@ impl ElGamal {
         &self.public_key
     }
 
+    /// 生成点の位数を取得
+    pub fn order(&self) -> &BigUint {
+        &self.order
+    }
+
     /// メッセージを暗号化
     pub fn encrypt(&self, message: &Point, r: Option<i64>) -> Result<Ciphertext, ProtocolError> {
-        let order = self
-            .curve
-            .point_order(&self.generator)
-            .map_err(|_| ProtocolError::InvalidParameters)?;
-
         // ランダムなrを生成
-        let r = r.unwrap_or_else(|| generate_private_key(order));
+        let r = r
+            .map(BigInt::from)
+            .unwrap_or_else(|| BigInt::from(generate_private_key(&self.order)));
 
         // c1 = rG を計算
-        let c1 = (self.generator.clone() * r).map_err(|_| ProtocolError::OperationFailed)?;
+        let c1 = self
+            .generator_table
+            .mul(&r)
+            .map_err(|_| ProtocolError::OperationFailed)?;
 
         // c2 = M + rB を計算
-        let rb = (self.public_key.clone() * r).map_err(|_| ProtocolError::OperationFailed)?;
+        let rb = (self.public_key.clone() * &r).map_err(|_| ProtocolError::OperationFailed)?;
         let c2 = (message.clone() + rb).map_err(|_| ProtocolError::OperationFailed)?;
 
         Ok(Ciphertext { c1, c2 })
@@ -62,7 +84,7 @@ impl ElGamal {
     /// 暗号文を復号
     pub fn decrypt(&self, ciphertext: &Ciphertext) -> Result<Point, ProtocolError> {
         // -kC1 を計算
-        let neg_kc1 = (ciphertext.c1.clone() * self.private_key)
+        let neg_kc1 = (ciphertext.c1.clone() * &self.private_key)
             .map_err(|_| ProtocolError::OperationFailed)
             .map(|p| -p)?;
 
@@ -148,4 +170,59 @@ mod tests {
         assert_eq!(sum_decrypted.x, expected_sum.x);
         assert_eq!(sum_decrypted.y, expected_sum.y);
     }
+
+    #[test]
+    fn test_weighted_homomorphic_sum() {
+        let (curve, generator) = setup_test_curve();
+        let alice = ElGamal::new(curve.clone(), generator.clone()).unwrap();
+
+        let messages = [
+            curve.point(192, 105).unwrap(),
+            curve.point(17, 56).unwrap(),
+            curve.point(47, 71).unwrap(),
+        ];
+        let weights = [BigInt::from(3), BigInt::from(5), BigInt::from(-2)];
+        let ciphertexts: Vec<Ciphertext> = messages
+            .iter()
+            .zip([17, 19, 23])
+            .map(|(m, r)| alice.encrypt(m, Some(r)).unwrap())
+            .collect();
+
+        // Σ wᵢ·Cᵢ を成分ごとに複数スカラー倍算で計算
+        let weighted = |select: fn(&Ciphertext) -> &Point| {
+            let terms: Vec<(Point, BigInt)> = ciphertexts
+                .iter()
+                .zip(&weights)
+                .map(|(c, w)| (select(c).clone(), w.clone()))
+                .collect();
+            Point::multi_scalar_mul(&terms).unwrap()
+        };
+        let sum_encrypted = Ciphertext {
+            c1: weighted(|c| &c.c1),
+            c2: weighted(|c| &c.c2),
+        };
+
+        let terms: Vec<(Point, BigInt)> = messages.into_iter().zip(weights).collect();
+        let expected = Point::multi_scalar_mul(&terms).unwrap();
+        assert_eq!(alice.decrypt(&sum_encrypted).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_new_prime_order() {
+        let (curve, _) = setup_test_curve();
+        let generator = curve.find_generator(&BigUint::from(7u32)).unwrap();
+        let alice = ElGamal::new_prime_order(curve.clone(), generator).unwrap();
+        assert_eq!(alice.order(), &BigUint::from(7u32));
+
+        let message = curve.point(192, 105).unwrap();
+        let ciphertext = alice.encrypt(&message, None).unwrap();
+        assert_eq!(alice.decrypt(&ciphertext).unwrap(), message);
+
+        // 位数42の点は拒否する
+        let composite = curve.point(192, 105).unwrap();
+        assert!(matches!(
+            ElGamal::new_prime_order(curve, composite),
+            Err(ProtocolError::InvalidParameters)
+        ));
+    }
 }
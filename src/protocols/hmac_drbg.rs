@@ -0,0 +1,128 @@
+use sha2::digest::core_api::BlockSizeUser;
+use sha2::Digest;
+
+/// HMAC (RFC 2104) を計算
+///
+/// `data` の各要素を連結したものを入力とする。
+pub fn hmac<D: Digest + BlockSizeUser>(key: &[u8], data: &[&[u8]]) -> Vec<u8> {
+    let block_size = D::block_size();
+
+    // ブロック長より長い鍵はハッシュしてから使う
+    let mut padded_key = if key.len() > block_size {
+        D::digest(key).to_vec()
+    } else {
+        key.to_vec()
+    };
+    padded_key.resize(block_size, 0);
+
+    let mut inner = D::new();
+    inner.update(padded_key.iter().map(|b| b ^ 0x36).collect::<Vec<u8>>());
+    for chunk in data {
+        inner.update(chunk);
+    }
+
+    let mut outer = D::new();
+    outer.update(padded_key.iter().map(|b| b ^ 0x5c).collect::<Vec<u8>>());
+    outer.update(inner.finalize());
+    outer.finalize().to_vec()
+}
+
+/// HMAC-DRBG (NIST SP 800-90A)
+pub struct HmacDrbg<D> {
+    key: Vec<u8>,
+    value: Vec<u8>,
+    _digest: std::marker::PhantomData<D>,
+}
+
+/// HMAC-DRBGの生成と出力を定義
+impl<D: Digest + BlockSizeUser> HmacDrbg<D> {
+    /// シード (エントロピー入力・ナンス・個別化文字列を連結したもの) から初期化
+    pub fn new(seed: &[&[u8]]) -> Self {
+        let len = <D as Digest>::output_size();
+        let mut drbg = Self {
+            key: vec![0x00; len],
+            value: vec![0x01; len],
+            _digest: std::marker::PhantomData,
+        };
+        drbg.update(seed);
+        drbg
+    }
+
+    /// 内部状態 (K, V) を更新
+    ///
+    /// K = HMAC(K, V || 0x00 || data), V = HMAC(K, V) を行い、
+    /// data が空でなければ 0x01 で同じ処理を繰り返す。
+    fn update(&mut self, data: &[&[u8]]) {
+        for separator in [0x00u8, 0x01] {
+            let mut input: Vec<&[u8]> = vec![&self.value, std::slice::from_ref(&separator)];
+            input.extend_from_slice(data);
+            self.key = hmac::<D>(&self.key, &input);
+            self.value = hmac::<D>(&self.key, &[&self.value]);
+
+            if data.iter().all(|chunk| chunk.is_empty()) {
+                break;
+            }
+        }
+    }
+
+    /// 指定したバイト数の疑似乱数を生成
+    pub fn generate(&mut self, len: usize) -> Vec<u8> {
+        let mut output = Vec::with_capacity(len);
+        while output.len() < len {
+            self.value = hmac::<D>(&self.key, &[&self.value]);
+            output.extend_from_slice(&self.value);
+        }
+        output.truncate(len);
+        self.update(&[]);
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocols::hex_bytes;
+    use sha2::{Sha256, Sha512};
+
+    #[test]
+    fn test_hmac_rfc4231() {
+        // RFC 4231 Test Case 2
+        let data: &[u8] = b"what do ya want for nothing?";
+        assert_eq!(
+            hmac::<Sha256>(b"Jefe", &[data]),
+            hex_bytes("5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843")
+        );
+        // 入力を分割しても結果は同じ
+        assert_eq!(
+            hmac::<Sha256>(b"Jefe", &[&data[..4], &data[4..]]),
+            hmac::<Sha256>(b"Jefe", &[data])
+        );
+        assert_eq!(
+            hmac::<Sha512>(b"Jefe", &[data]),
+            hex_bytes(concat!(
+                "164b7a7bfcf819e2e395fbe73b56e0a387bd64222e831fd610270cd7ea250554",
+                "9758bf75c05a994a6d034f65f8f0e6fdcaeab1a34d4a6b4b636e070a38bce737"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_hmac_long_key() {
+        // RFC 4231 Test Case 6: ブロック長を超える鍵
+        let key = [0xaa; 131];
+        let data: &[u8] = b"Test Using Larger Than Block-Size Key - Hash Key First";
+        assert_eq!(
+            hmac::<Sha256>(&key, &[data]),
+            hex_bytes("60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54")
+        );
+    }
+
+    #[test]
+    fn test_drbg_is_deterministic() {
+        let mut a = HmacDrbg::<Sha256>::new(&[b"seed", b"nonce"]);
+        let mut b = HmacDrbg::<Sha256>::new(&[b"seednonce"]);
+        let first = a.generate(48);
+        assert_eq!(first, b.generate(48));
+        assert_ne!(a.generate(48), first);
+    }
+}
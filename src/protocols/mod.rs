@@ -1,6 +1,13 @@
 pub mod diffie_hellman;
+pub mod ecdsa;
+pub mod ed25519;
 pub mod elgamal;
+pub mod hmac_drbg;
+pub mod nonce;
+pub mod schnorr;
+pub mod xdh;
 
+use num_bigint::BigUint;
 use rand::Rng;
 use thiserror::Error;
 
@@ -10,10 +17,174 @@ pub enum ProtocolError {
     InvalidParameters,
     #[error("Operation failed")]
     OperationFailed,
+    #[error("Invalid encoding")]
+    InvalidEncoding,
+    #[error("Point is not on the curve")]
+    PointNotOnCurve,
 }
 
 /// 鍵生成のためのヘルパー関数
-pub(crate) fn generate_private_key(order: i64) -> i64 {
+///
+/// [1, order) の範囲から一様に乱数を選ぶ。order は2以上でなければならない。
+pub(crate) fn generate_private_key(order: &BigUint) -> BigUint {
+    assert!(*order > BigUint::from(1u32), "order must be at least 2");
     let mut rng = rand::rng();
-    rng.random_range(1..order)
+    let bits = order.bits();
+    let mut bytes = vec![0u8; bits.div_ceil(8) as usize];
+
+    // 棄却サンプリング
+    loop {
+        rng.fill(&mut bytes[..]);
+        let candidate = BigUint::from_bytes_be(&bytes) >> (bytes.len() as u64 * 8 - bits);
+        if candidate > BigUint::ZERO && candidate < *order {
+            return candidate;
+        }
+    }
+}
+
+/// ハッシュ値を整数に変換する (SEC 1 / RFC 6979 の bits2int)
+///
+/// ハッシュのビット長が位数のビット長より長い場合は、上位ビットだけを使う。
+pub(crate) fn bits_to_int(hash: &[u8], order: &BigUint) -> BigUint {
+    let value = BigUint::from_bytes_be(hash);
+    let hash_bits = hash.len() as u64 * 8;
+    let order_bits = order.bits();
+    if hash_bits > order_bits {
+        value >> (hash_bits - order_bits)
+    } else {
+        value
+    }
+}
+
+/// 生成点の位数を計算し、鍵生成に使えるか確認する
+///
+/// 生成点が別の曲線上にある場合は `PointNotOnCurve`、位数の計算に失敗した場合は
+/// `OperationFailed`、位数が1以下の場合は `InvalidParameters` を返す。
+pub(crate) fn generator_order(
+    curve: &crate::curve::Curve,
+    generator: &crate::point::Point,
+) -> Result<BigUint, ProtocolError> {
+    if generator.a != curve.a || generator.b != curve.b {
+        return Err(ProtocolError::PointNotOnCurve);
+    }
+    let order = curve
+        .point_order(generator)
+        .map_err(|_| ProtocolError::OperationFailed)?;
+    if order <= BigUint::from(1u32) {
+        return Err(ProtocolError::InvalidParameters);
+    }
+    Ok(order)
+}
+
+/// 生成点の位数が素数であることを要求する
+///
+/// 位数が合成数だとPohlig–Hellman法で離散対数が小さな部分群ごとに解かれてしまう。
+pub(crate) fn prime_generator_order(
+    curve: &crate::curve::Curve,
+    generator: &crate::point::Point,
+) -> Result<BigUint, ProtocolError> {
+    let order = generator_order(curve, generator)?;
+    if !crate::arith::is_probable_prime(&order) {
+        return Err(ProtocolError::InvalidParameters);
+    }
+    Ok(order)
+}
+
+/// Curve25519 のスカラーのクランプ (X25519 と Ed25519 で共通)
+///
+/// 下位3ビットを0にして余因子8の倍数にし、ビット254を立ててビット255を落とす。
+pub fn clamp_scalar_25519(scalar: &[u8; 32]) -> BigUint {
+    let mut bytes = *scalar;
+    bytes[0] &= 0xF8;
+    bytes[31] &= 0x7F;
+    bytes[31] |= 0x40;
+    BigUint::from_bytes_le(&bytes)
+}
+
+/// Curve448 のスカラーのクランプ (X448)
+///
+/// 下位2ビットを0にして余因子4の倍数にし、ビット447を立てる。
+pub fn clamp_scalar_448(scalar: &[u8; 56]) -> BigUint {
+    let mut bytes = *scalar;
+    bytes[0] &= 0xFC;
+    bytes[55] |= 0x80;
+    BigUint::from_bytes_le(&bytes)
+}
+
+/// 16進文字列をバイト列に変換（テストベクタ用）
+#[cfg(test)]
+pub(crate) fn hex_bytes(value: &str) -> Vec<u8> {
+    (0..value.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&value[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+/// 16進文字列を整数に変換（テストベクタ用）
+#[cfg(test)]
+pub(crate) fn hex_uint(value: &str) -> BigUint {
+    BigUint::from_bytes_be(&hex_bytes(value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_private_key_range() {
+        for order in [2u32, 7, 42, 256, 257] {
+            let order = BigUint::from(order);
+            for _ in 0..50 {
+                let key = generate_private_key(&order);
+                assert!(key >= BigUint::from(1u32) && key < order);
+            }
+        }
+    }
+
+    #[test]
+    fn test_generator_order_errors() {
+        let curve = crate::curve::Curve::new(0, 7, 223).unwrap();
+        let other = crate::curve::Curve::new(1, 7, 223).unwrap();
+        let generator = curve.point(15, 86).unwrap();
+
+        assert_eq!(
+            generator_order(&curve, &generator).unwrap(),
+            BigUint::from(7u32)
+        );
+        assert!(matches!(
+            generator_order(&other, &generator),
+            Err(ProtocolError::PointNotOnCurve)
+        ));
+        assert!(matches!(
+            generator_order(&curve, &curve.infinity_point()),
+            Err(ProtocolError::InvalidParameters)
+        ));
+    }
+
+    #[test]
+    fn test_prime_generator_order() {
+        let curve = crate::curve::Curve::new(0, 7, 223).unwrap();
+        // (15, 86) の位数は7、(192, 105) の位数は42
+        let prime_order = curve.point(15, 86).unwrap();
+        let composite_order = curve.point(192, 105).unwrap();
+        assert_eq!(
+            prime_generator_order(&curve, &prime_order).unwrap(),
+            BigUint::from(7u32)
+        );
+        assert!(matches!(
+            prime_generator_order(&curve, &composite_order),
+            Err(ProtocolError::InvalidParameters)
+        ));
+    }
+
+    #[test]
+    fn test_clamping() {
+        let scalar = clamp_scalar_25519(&[0xFF; 32]);
+        assert_eq!(&scalar % 8u32, BigUint::ZERO);
+        assert_eq!(scalar.bits(), 255);
+        assert_eq!(clamp_scalar_25519(&[0; 32]), BigUint::from(1u32) << 254u32);
+        let scalar = clamp_scalar_448(&[0xFF; 56]);
+        assert_eq!(&scalar % 4u32, BigUint::ZERO);
+        assert_eq!(clamp_scalar_448(&[0; 56]), BigUint::from(1u32) << 447u32);
+    }
 }
@@ -0,0 +1,159 @@
+use super::hmac_drbg::HmacDrbg;
+use super::{bits_to_int, generate_private_key};
+use num_bigint::BigUint;
+use num_traits::Zero;
+use sha2::digest::core_api::BlockSizeUser;
+use sha2::Digest;
+use std::marker::PhantomData;
+
+/// 署名用のナンスの生成方法
+///
+/// 署名方式は `nonces` が返す候補を順に使い、r = 0 などで失敗した場合は次の候補を使う。
+pub trait NonceSource {
+    /// 秘密鍵とメッセージのハッシュ値から、[1, order) のナンスの候補を順に返す
+    fn nonces(
+        &self,
+        private_key: &BigUint,
+        msg_hash: &[u8],
+        order: &BigUint,
+    ) -> Box<dyn Iterator<Item = BigUint>>;
+}
+
+/// 乱数生成器からナンスを選ぶ
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RandomNonce;
+
+impl NonceSource for RandomNonce {
+    fn nonces(
+        &self,
+        _private_key: &BigUint,
+        _msg_hash: &[u8],
+        order: &BigUint,
+    ) -> Box<dyn Iterator<Item = BigUint>> {
+        let order = order.clone();
+        Box::new(std::iter::repeat_with(move || generate_private_key(&order)))
+    }
+}
+
+/// RFC 6979 の決定的なナンス
+///
+/// 秘密鍵とメッセージのハッシュ値をシードとする HMAC-DRBG からナンスを導出する。
+/// 同じ入力には同じナンスを返すので、乱数生成器の不具合で秘密鍵が漏れることがない。
+#[derive(Debug, Clone, Copy)]
+pub struct Rfc6979<D> {
+    _digest: PhantomData<D>,
+}
+
+impl<D> Rfc6979<D> {
+    pub fn new() -> Self {
+        Self {
+            _digest: PhantomData,
+        }
+    }
+}
+
+impl<D> Default for Rfc6979<D> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<D: Digest + BlockSizeUser + 'static> NonceSource for Rfc6979<D> {
+    fn nonces(
+        &self,
+        private_key: &BigUint,
+        msg_hash: &[u8],
+        order: &BigUint,
+    ) -> Box<dyn Iterator<Item = BigUint>> {
+        let order = order.clone();
+        let len = order.bits().div_ceil(8) as usize;
+
+        // int2octets(x) || bits2octets(h₁)
+        let seed_key = int_to_octets(private_key, len);
+        let seed_hash = int_to_octets(&(bits_to_int(msg_hash, &order) % &order), len);
+        let mut drbg = HmacDrbg::<D>::new(&[&seed_key, &seed_hash]);
+
+        // 範囲外の候補は捨てて、DRBGの出力を続けて使う
+        Box::new(std::iter::repeat_with(move || loop {
+            let k = bits_to_int(&drbg.generate(len), &order);
+            if !k.is_zero() && k < order {
+                return k;
+            }
+        }))
+    }
+}
+
+/// 整数を固定長のビッグエンディアンに変換 (RFC 6979 の int2octets)
+fn int_to_octets(value: &BigUint, len: usize) -> Vec<u8> {
+    let bytes = value.to_bytes_be();
+    let mut padded = vec![0u8; len.saturating_sub(bytes.len())];
+    padded.extend(bytes);
+    padded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::curve::Curve;
+    use crate::protocols::hex_uint;
+    use sha2::Sha256;
+
+    #[test]
+    fn test_rfc6979_p256_nonces() {
+        // RFC 6979 A.2.5, SHA-256
+        let order = Curve::p256().order;
+        let private_key =
+            hex_uint("C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721");
+        let source = Rfc6979::<Sha256>::new();
+
+        for (message, expected) in [
+            (
+                "sample",
+                "A6E3C57DD01ABE90086538398355DD4C3B17AA873382B0F24D6129493D8AAD60",
+            ),
+            (
+                "test",
+                "D16B6AE827F17175E040871A1C7EC3500192C4C92677336EC2537ACAEE0008E0",
+            ),
+        ] {
+            let msg_hash = Sha256::digest(message.as_bytes());
+            let k = source
+                .nonces(&private_key, &msg_hash, &order)
+                .next()
+                .unwrap();
+            assert_eq!(k, hex_uint(expected), "{message}");
+        }
+    }
+
+    #[test]
+    fn test_rfc6979_secp256k1_nonce() {
+        let order = Curve::secp256k1().order;
+        let msg_hash = Sha256::digest(b"Satoshi Nakamoto");
+        let k = Rfc6979::<Sha256>::new()
+            .nonces(&BigUint::from(1u32), &msg_hash, &order)
+            .next()
+            .unwrap();
+        assert_eq!(
+            k,
+            hex_uint("8F8A276C19F4149656B280621E358CCE24F5F52542772691EE69063B74F15D15")
+        );
+    }
+
+    #[test]
+    fn test_nonces_are_in_range() {
+        let order = BigUint::from(7u32);
+        let key = BigUint::from(3u32);
+        let sources: [&dyn NonceSource; 2] = [&RandomNonce, &Rfc6979::<Sha256>::new()];
+        for source in sources {
+            for k in source.nonces(&key, &[5], &order).take(20) {
+                assert!(!k.is_zero() && k < order);
+            }
+        }
+
+        // 決定的なナンスは同じ入力に対して同じ列になる
+        let source = Rfc6979::<Sha256>::new();
+        let first: Vec<BigUint> = source.nonces(&key, &[5], &order).take(5).collect();
+        let second: Vec<BigUint> = source.nonces(&key, &[5], &order).take(5).collect();
+        assert_eq!(first, second);
+    }
+}
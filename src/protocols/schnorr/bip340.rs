@@ -0,0 +1,463 @@
+//! BIP-340 (secp256k1 上のSchnorr署名)
+//!
+//! 公開鍵と R は x 座標のみ (32バイト) で表し、y 座標が偶数の点を代表元とする。
+
+use super::super::{generate_private_key, ProtocolError};
+use crate::curve::{Curve, CurveParams};
+use crate::point::Point;
+use num_bigint::{BigInt, BigUint};
+use num_traits::Zero;
+use rand::Rng;
+use sha2::{Digest, Sha256};
+
+/// BIP-340 署名 (R の x 座標 r と s)
+///
+/// 署名か `from_bytes` でのみ生成するので、r, s は常に 2²⁵⁶ 未満に収まる。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Signature {
+    r: BigUint,
+    s: BigUint,
+}
+
+/// BIP-340 の署名鍵
+pub struct SigningKey {
+    generator: Point,
+    order: BigUint,
+    private_key: BigUint,
+    verifying_key: VerifyingKey,
+}
+
+/// BIP-340 の x-only 公開鍵
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerifyingKey {
+    public_key: Point,
+}
+
+/// タグ付きハッシュ SHA-256(SHA-256(tag) || SHA-256(tag) || data)
+pub fn tagged_hash(tag: &str, data: &[&[u8]]) -> [u8; 32] {
+    let tag_hash = Sha256::digest(tag.as_bytes());
+    let mut hasher = Sha256::new().chain_update(tag_hash).chain_update(tag_hash);
+    for part in data {
+        hasher.update(part);
+    }
+    hasher.finalize().into()
+}
+
+/// 署名鍵の生成と署名を定義
+impl SigningKey {
+    /// 新しい署名鍵をランダムに生成
+    pub fn new() -> Result<Self, ProtocolError> {
+        let order = Curve::secp256k1().order;
+        Self::from_private_key(generate_private_key(&order))
+    }
+
+    /// 32バイトの秘密鍵から署名鍵を作成
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ProtocolError> {
+        if bytes.len() != 32 {
+            return Err(ProtocolError::InvalidEncoding);
+        }
+        Self::from_private_key(BigUint::from_bytes_be(bytes))
+    }
+
+    /// 秘密鍵 d' ∈ [1, n) から署名鍵を作成
+    ///
+    /// P = d'G の y 座標が奇数なら d = n - d' に置き換え、公開鍵を偶数の y に揃える。
+    pub fn from_private_key(private_key: BigUint) -> Result<Self, ProtocolError> {
+        let CurveParams {
+            generator, order, ..
+        } = Curve::secp256k1();
+        if private_key.is_zero() || private_key >= order {
+            return Err(ProtocolError::InvalidParameters);
+        }
+
+        // 秘密鍵のスカラー倍算はMontgomeryラダーで行う
+        let point =
+            (generator.clone() * &private_key).map_err(|_| ProtocolError::OperationFailed)?;
+        let (private_key, public_key) = if has_even_y(&point) {
+            (private_key, point)
+        } else {
+            (&order - private_key, -point)
+        };
+
+        Ok(Self {
+            generator,
+            order,
+            private_key,
+            verifying_key: VerifyingKey { public_key },
+        })
+    }
+
+    /// 検証鍵を取得
+    pub fn verifying_key(&self) -> &VerifyingKey {
+        &self.verifying_key
+    }
+
+    /// メッセージに署名 (補助乱数はランダムに選ぶ)
+    pub fn sign(&self, msg: &[u8]) -> Result<Signature, ProtocolError> {
+        let mut aux_rand = [0u8; 32];
+        rand::rng().fill(&mut aux_rand);
+        self.sign_with_aux_rand(msg, &aux_rand)
+    }
+
+    /// 補助乱数 a を指定して署名
+    ///
+    /// k = H_nonce((d ⊕ H_aux(a)) || P || m) を偶数の y に揃え、
+    /// e = H_challenge(R || P || m), s = k + e·d mod n を計算する。
+    /// 故障攻撃に備え、返す前に署名を検証する。
+    pub fn sign_with_aux_rand(
+        &self,
+        msg: &[u8],
+        aux_rand: &[u8; 32],
+    ) -> Result<Signature, ProtocolError> {
+        let n = &self.order;
+        let public_key = self.verifying_key.to_bytes();
+
+        let mut masked = to_bytes32(&self.private_key);
+        let aux_hash = tagged_hash("BIP0340/aux", &[aux_rand]);
+        for (byte, mask) in masked.iter_mut().zip(aux_hash) {
+            *byte ^= mask;
+        }
+        let nonce =
+            BigUint::from_bytes_be(&tagged_hash("BIP0340/nonce", &[&masked, &public_key, msg])) % n;
+        if nonce.is_zero() {
+            return Err(ProtocolError::OperationFailed);
+        }
+
+        let point =
+            (self.generator.clone() * &nonce).map_err(|_| ProtocolError::OperationFailed)?;
+        let nonce = if has_even_y(&point) { nonce } else { n - nonce };
+        let r = point
+            .x
+            .as_ref()
+            .ok_or(ProtocolError::OperationFailed)?
+            .value()
+            .clone();
+
+        let e = challenge(&r, &public_key, msg, n);
+        let s = (nonce + e * &self.private_key) % n;
+        let signature = Signature { r, s };
+        if !self.verifying_key.verify(msg, &signature) {
+            return Err(ProtocolError::OperationFailed);
+        }
+        Ok(signature)
+    }
+}
+
+/// 検証鍵のエンコードと検証を定義
+impl VerifyingKey {
+    /// 32バイトの x 座標から公開鍵を復元 (y 座標は偶数を選ぶ)
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ProtocolError> {
+        if bytes.len() != 32 {
+            return Err(ProtocolError::InvalidEncoding);
+        }
+        let public_key = lift_x(&Curve::secp256k1().curve, &BigUint::from_bytes_be(bytes))
+            .ok_or(ProtocolError::InvalidEncoding)?;
+        Ok(Self { public_key })
+    }
+
+    /// 32バイトの x 座標にエンコード
+    pub fn to_bytes(&self) -> [u8; 32] {
+        to_bytes32(
+            self.public_key
+                .x
+                .as_ref()
+                .expect("public key is not infinity")
+                .value(),
+        )
+    }
+
+    /// 公開鍵の点 (y 座標は偶数) を取得
+    pub fn public_key(&self) -> &Point {
+        &self.public_key
+    }
+
+    /// 署名を検証
+    ///
+    /// R = sG - eP が無限遠点でなく、y 座標が偶数で、x 座標が r に一致することを確認する。
+    pub fn verify(&self, msg: &[u8], signature: &Signature) -> bool {
+        let params = Curve::secp256k1();
+        let n = &params.order;
+        let Signature { r, s } = signature;
+        if *r >= params.curve.prime || s >= n {
+            return false;
+        }
+
+        let e = challenge(r, &self.to_bytes(), msg, n);
+        let terms = [
+            (params.generator, BigInt::from(s.clone())),
+            (self.public_key.clone(), -BigInt::from(e)),
+        ];
+        match Point::multi_scalar_mul(&terms) {
+            Ok(point) => has_even_y(&point) && point.x.is_some_and(|x| x.value() == r),
+            Err(_) => false,
+        }
+    }
+}
+
+/// 署名のエンコードを定義
+impl Signature {
+    /// R の x 座標 r を取得
+    pub fn r(&self) -> &BigUint {
+        &self.r
+    }
+
+    /// s を取得
+    pub fn s(&self) -> &BigUint {
+        &self.s
+    }
+
+    /// r || s の64バイトにエンコード
+    pub fn to_bytes(&self) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&to_bytes32(&self.r));
+        bytes[32..].copy_from_slice(&to_bytes32(&self.s));
+        bytes
+    }
+
+    /// 64バイトの r || s からデコード
+    ///
+    /// r < p, s < n の確認は検証時に行う。
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ProtocolError> {
+        if bytes.len() != 64 {
+            return Err(ProtocolError::InvalidEncoding);
+        }
+        Ok(Self {
+            r: BigUint::from_bytes_be(&bytes[..32]),
+            s: BigUint::from_bytes_be(&bytes[32..]),
+        })
+    }
+}
+
+/// 複数の署名をまとめて検証
+///
+/// ランダムな係数 a₁ = 1, aᵢ ∈ [1, n) を用いて
+/// (Σ aᵢsᵢ)G = Σ aᵢRᵢ + Σ aᵢeᵢPᵢ を1回の複数スカラー倍算で確認する。
+/// すべての署名が有効なら `true` を返し、1つでも無効なら (高い確率で) `false` を返す。
+pub fn verify_batch(items: &[(&VerifyingKey, &[u8], &Signature)]) -> bool {
+    let params = Curve::secp256k1();
+    let n = &params.order;
+
+    let mut generator_scalar = BigUint::zero();
+    let mut terms = Vec::with_capacity(2 * items.len() + 1);
+    for (i, (key, msg, signature)) in items.iter().enumerate() {
+        let Signature { r, s } = signature;
+        if *r >= params.curve.prime || s >= n {
+            return false;
+        }
+        let Some(point) = lift_x(&params.curve, r) else {
+            return false;
+        };
+
+        let coefficient = if i == 0 {
+            BigUint::from(1u32)
+        } else {
+            generate_private_key(n)
+        };
+        let e = challenge(r, &key.to_bytes(), msg, n);
+        generator_scalar = (generator_scalar + &coefficient * s) % n;
+        terms.push((point, -BigInt::from(coefficient.clone())));
+        terms.push((key.public_key.clone(), -BigInt::from(coefficient * e % n)));
+    }
+    terms.push((params.generator, BigInt::from(generator_scalar)));
+
+    Point::multi_scalar_mul(&terms).is_ok_and(|point| point.is_infinity())
+}
+
+/// チャレンジ e = H_challenge(r || P || m) mod n
+fn challenge(r: &BigUint, public_key: &[u8; 32], msg: &[u8], order: &BigUint) -> BigUint {
+    let hash = tagged_hash("BIP0340/challenge", &[&to_bytes32(r), public_key, msg]);
+    BigUint::from_bytes_be(&hash) % order
+}
+
+/// x < p を満たし、y 座標が偶数の点を復元
+fn lift_x(curve: &Curve, x: &BigUint) -> Option<Point> {
+    if *x >= curve.prime {
+        return None;
+    }
+    curve.lift_x(x.clone(), false).ok()
+}
+
+/// y 座標が偶数かどうか (無限遠点は `false`)
+fn has_even_y(point: &Point) -> bool {
+    point.y.as_ref().is_some_and(|y| !y.value().bit(0))
+}
+
+/// 32バイトのビッグエンディアン表現
+fn to_bytes32(value: &BigUint) -> [u8; 32] {
+    let bytes = value.to_bytes_be();
+    let mut out = [0u8; 32];
+    out[32 - bytes.len()..].copy_from_slice(&bytes);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocols::hex_bytes;
+
+    #[test]
+    fn test_bip340_signing_vectors() {
+        // BIP-340 test-vectors.csv (index 0-3, 15)
+        for (secret_key, public_key, aux_rand, msg, expected) in [
+            (
+                "0000000000000000000000000000000000000000000000000000000000000003",
+                "F9308A019258C31049344F85F89D5229B531C845836F99B08601F113BCE036F9",
+                "0000000000000000000000000000000000000000000000000000000000000000",
+                "0000000000000000000000000000000000000000000000000000000000000000",
+                "E907831F80848D1069A5371B402410364BDF1C5F8307B0084C55F1CE2DCA8215\
+                 25F66A4A85EA8B71E482A74F382D2CE5EBEEE8FDB2172F477DF4900D310536C0",
+            ),
+            (
+                "B7E151628AED2A6ABF7158809CF4F3C762E7160F38B4DA56A784D9045190CFEF",
+                "DFF1D77F2A671C5F36183726DB2341BE58FEAE1DA2DECED843240F7B502BA659",
+                "0000000000000000000000000000000000000000000000000000000000000001",
+                "243F6A8885A308D313198A2E03707344A4093822299F31D0082EFA98EC4E6C89",
+                "6896BD60EEAE296DB48A229FF71DFE071BDE413E6D43F917DC8DCF8C78DE3341\
+                 8906D11AC976ABCCB20B091292BFF4EA897EFCB639EA871CFA95F6DE339E4B0A",
+            ),
+            (
+                "C90FDAA22168C234C4C6628B80DC1CD129024E088A67CC74020BBEA63B14E5C9",
+                "DD308AFEC5777E13121FA72B9CC1B7CC0139715309B086C960E18FD969774EB8",
+                "C87AA53824B4D7AE2EB035A2B5BBBCCC080E76CDC6D1692C4B0B62D798E6D906",
+                "7E2D58D8B3BCDF1ABADEC7829054F90DDA9805AAB56C77333024B9D0A508B75C",
+                "5831AAEED7B44BB74E5EAB94BA9D4294C49BCF2A60728D8B4C200F50DD313C1B\
+                 AB745879A5AD954A72C45A91C3A51D3C7ADEA98D82F8481E0E1E03674A6F3FB7",
+            ),
+            (
+                "0B432B2677937381AEF05BB02A66ECD012773062CF3FA2549E44F58ED2401710",
+                "25D1DFF95105F5253C4022F628A996AD3A0D95FBF21D468A1B33F8C160D8F517",
+                "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF",
+                "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF",
+                "7EB0509757E246F19449885651611CB965ECC1A187DD51B64FDA1EDC9637D5EC\
+                 97582B9CB13DB3933705B32BA982AF5AF25FD78881EBB32771FC5922EFC66EA3",
+            ),
+            (
+                "0340034003400340034003400340034003400340034003400340034003400340",
+                "778CAA53B4393AC467774D09497A87224BF9FAB6F6E68B23086497324D6FD117",
+                "0000000000000000000000000000000000000000000000000000000000000000",
+                "",
+                "71535DB165ECD9FBBC046E5FFAEA61186BB6AD436732FCCC25291A55895464CF\
+                 6069CE26BF03466228F19A3A62DB8A649F2D560FAC652827D1AF0574E427AB63",
+            ),
+        ] {
+            let key = SigningKey::from_bytes(&hex_bytes(secret_key)).unwrap();
+            assert_eq!(
+                key.verifying_key().to_bytes().to_vec(),
+                hex_bytes(public_key)
+            );
+
+            let aux_rand: [u8; 32] = hex_bytes(aux_rand).try_into().unwrap();
+            let msg = hex_bytes(msg);
+            let signature = key.sign_with_aux_rand(&msg, &aux_rand).unwrap();
+            assert_eq!(signature.to_bytes().to_vec(), hex_bytes(expected));
+
+            let verifying_key = VerifyingKey::from_bytes(&hex_bytes(public_key)).unwrap();
+            assert!(verifying_key.verify(&msg, &signature));
+        }
+    }
+
+    #[test]
+    fn test_bip340_verification_vectors() {
+        // index 4: R の x 座標の上位バイトが0でも有効
+        let public_key = VerifyingKey::from_bytes(&hex_bytes(
+            "D69C3509BB99E412E68B0FE8544E72837DFA30746D8BE2AA65975F29D22DC7B9",
+        ))
+        .unwrap();
+        let msg = hex_bytes("4DF3C3F68FCC83B27E9D42C90431A72499F17875C81A599B566C9889B9696703");
+        let signature = Signature::from_bytes(&hex_bytes(
+            "00000000000000000000003B78CE563F89A0ED9414F5AA28AD0D96D6795F9C63\
+             76AFB1548AF603B3EB45C9F8207DEE1060CB71C04E80F593060B07D28308D7F4",
+        ))
+        .unwrap();
+        assert!(public_key.verify(&msg, &signature));
+
+        // index 5: 曲線上にない公開鍵
+        assert!(VerifyingKey::from_bytes(&hex_bytes(
+            "EEFDEA4CDB677750A420FEE807EACF21EB9898AE79B9768766E4FAA04A2D4A34",
+        ))
+        .is_err());
+    }
+
+    #[test]
+    fn test_verify_rejects_invalid_signatures() {
+        let params = Curve::secp256k1();
+        let key = SigningKey::new().unwrap();
+        let verifying_key = key.verifying_key();
+        let signature = key.sign(b"message").unwrap();
+        assert!(verifying_key.verify(b"message", &signature));
+        assert!(!verifying_key.verify(b"massage", &signature));
+
+        // R の y 座標が奇数になるよう s を変える: sG - eP = -R
+        let e = challenge(
+            &signature.r,
+            &verifying_key.to_bytes(),
+            b"message",
+            &params.order,
+        );
+        let d = &key.private_key;
+        let negated = Signature {
+            r: signature.r.clone(),
+            s: (&params.order * 2u32 - &signature.s + e * d * 2u32 % &params.order) % &params.order,
+        };
+        assert!(!verifying_key.verify(b"message", &negated));
+
+        // 範囲外の r, s
+        let large_r = Signature {
+            r: params.curve.prime.clone(),
+            s: signature.s.clone(),
+        };
+        assert!(!verifying_key.verify(b"message", &large_r));
+        let large_s = Signature {
+            r: signature.r.clone(),
+            s: &signature.s + &params.order,
+        };
+        assert!(!verifying_key.verify(b"message", &large_s));
+        assert!(Signature::from_bytes(&signature.to_bytes()[1..]).is_err());
+
+        // 範囲外の r, s もデコードでき、そのまま再エンコードできる
+        let max = Signature::from_bytes(&[0xFF; 64]).unwrap();
+        assert_eq!(max.to_bytes(), [0xFF; 64]);
+        assert!(!verifying_key.verify(b"message", &max));
+    }
+
+    #[test]
+    fn test_even_y_normalization() {
+        // d' と n - d' は同じ x-only 公開鍵になる
+        let order = Curve::secp256k1().order;
+        let key = SigningKey::from_private_key(BigUint::from(3u32)).unwrap();
+        let negated = SigningKey::from_private_key(&order - 3u32).unwrap();
+        assert_eq!(key.verifying_key(), negated.verifying_key());
+        assert!(has_even_y(key.verifying_key().public_key()));
+        assert!(SigningKey::from_private_key(order).is_err());
+    }
+
+    #[test]
+    fn test_verify_batch() {
+        let keys: Vec<SigningKey> = (0..4).map(|_| SigningKey::new().unwrap()).collect();
+        let messages: Vec<Vec<u8>> = (0..4u8).map(|i| vec![i; i as usize + 1]).collect();
+        let signatures: Vec<Signature> = keys
+            .iter()
+            .zip(&messages)
+            .map(|(key, msg)| key.sign(msg).unwrap())
+            .collect();
+
+        let items: Vec<(&VerifyingKey, &[u8], &Signature)> = keys
+            .iter()
+            .zip(&messages)
+            .zip(&signatures)
+            .map(|((key, msg), signature)| (key.verifying_key(), msg.as_slice(), signature))
+            .collect();
+        assert!(verify_batch(&items));
+        assert!(verify_batch(&[]));
+
+        // 1つでもメッセージが違えば失敗する
+        let mut tampered = items.clone();
+        tampered[2].1 = b"other";
+        assert!(!verify_batch(&tampered));
+
+        // 署名を入れ替えても失敗する
+        let mut swapped = items.clone();
+        swapped[0].2 = &signatures[1];
+        assert!(!verify_batch(&swapped));
+    }
+}
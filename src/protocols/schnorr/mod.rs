@@ -0,0 +1,288 @@
+pub mod bip340;
+
+use super::nonce::{NonceSource, RandomNonce, Rfc6979};
+use super::{bits_to_int, generate_private_key, prime_generator_order, ProtocolError};
+use crate::arith::is_probable_prime;
+use crate::curve::{Curve, CurveParams};
+use crate::point::{CurvePoint, Point};
+use num_bigint::{BigInt, BigUint};
+use num_traits::Zero;
+use sha2::{Digest, Sha256};
+
+/// Schnorr署名 (R, s)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Signature<P = Point> {
+    pub r: P,
+    pub s: BigUint,
+}
+
+/// Schnorr署名の署名鍵
+///
+/// 点の型 `P` は `CurvePoint` を実装していればよく、Weierstrass形式でもEdwards形式でも使える。
+pub struct SigningKey<P = Point> {
+    order: BigUint,
+    private_key: BigUint,
+    verifying_key: VerifyingKey<P>,
+}
+
+/// Schnorr署名の検証鍵
+#[derive(Debug, Clone)]
+pub struct VerifyingKey<P = Point> {
+    generator: P,
+    order: BigUint,
+    public_key: P,
+}
+
+/// 短縮Weierstrass形式の曲線から署名鍵を生成
+impl SigningKey {
+    /// 新しい署名鍵をランダムに生成
+    ///
+    /// 生成点の位数を計算するので、小さな曲線向け。位数は素数でなければならない。
+    pub fn new(curve: Curve, generator: Point) -> Result<Self, ProtocolError> {
+        let order = prime_generator_order(&curve, &generator)?;
+        let private_key = generate_private_key(&order);
+        Self::from_private_key(&generator, &order, private_key)
+    }
+
+    /// 標準曲線のパラメータから署名鍵をランダムに生成
+    pub fn from_params(params: &CurveParams) -> Result<Self, ProtocolError> {
+        let private_key = generate_private_key(&params.order);
+        Self::from_private_key(&params.generator, &params.order, private_key)
+    }
+}
+
+/// 署名鍵の生成と署名を定義
+impl<P: CurvePoint> SigningKey<P> {
+    /// 秘密鍵 d ∈ [1, n) から署名鍵を作成
+    pub fn from_private_key(
+        generator: &P,
+        order: &BigUint,
+        private_key: BigUint,
+    ) -> Result<Self, ProtocolError> {
+        if private_key.is_zero() || private_key >= *order {
+            return Err(ProtocolError::InvalidParameters);
+        }
+
+        let public_key = generator
+            .mul_secret(&private_key)
+            .map_err(|_| ProtocolError::OperationFailed)?;
+        let verifying_key = VerifyingKey::new(generator, order, public_key)?;
+
+        Ok(Self {
+            order: order.clone(),
+            private_key,
+            verifying_key,
+        })
+    }
+
+    /// 検証鍵を取得
+    pub fn verifying_key(&self) -> &VerifyingKey<P> {
+        &self.verifying_key
+    }
+
+    /// メッセージに署名 (ナンスはランダムに選ぶ)
+    pub fn sign(&self, msg: &[u8]) -> Result<Signature<P>, ProtocolError> {
+        self.sign_with(msg, &RandomNonce)
+    }
+
+    /// RFC 6979 (HMAC-SHA-256) の決定的なナンスで署名
+    pub fn sign_deterministic(&self, msg: &[u8]) -> Result<Signature<P>, ProtocolError> {
+        self.sign_with(msg, &Rfc6979::<Sha256>::new())
+    }
+
+    /// 指定したナンスの生成方法で署名
+    ///
+    /// ナンスの導出にはメッセージの SHA-256 ハッシュ値を渡す。
+    pub fn sign_with(
+        &self,
+        msg: &[u8],
+        source: &dyn NonceSource,
+    ) -> Result<Signature<P>, ProtocolError> {
+        let msg_hash = Sha256::digest(msg);
+        for nonce in source.nonces(&self.private_key, &msg_hash, &self.order) {
+            if let Ok(signature) = self.sign_with_nonce(msg, &nonce) {
+                return Ok(signature);
+            }
+        }
+        Err(ProtocolError::OperationFailed)
+    }
+
+    /// 与えられたナンス k で署名
+    ///
+    /// R = kG, e = H(R || P || m), s = k + e·d mod n を計算する。
+    /// 同じ k を異なるメッセージに使うと秘密鍵が漏れるので、テスト以外には使わないこと。
+    pub fn sign_with_nonce(
+        &self,
+        msg: &[u8],
+        nonce: &BigUint,
+    ) -> Result<Signature<P>, ProtocolError> {
+        let n = &self.order;
+        if nonce.is_zero() || nonce >= n {
+            return Err(ProtocolError::InvalidParameters);
+        }
+
+        let r = self
+            .verifying_key
+            .generator
+            .mul_secret(nonce)
+            .map_err(|_| ProtocolError::OperationFailed)?;
+        let e = challenge(&r, &self.verifying_key.public_key, msg, n);
+        let s = (nonce + e * &self.private_key) % n;
+        Ok(Signature { r, s })
+    }
+}
+
+/// 検証鍵の生成と検証を定義
+impl<P: CurvePoint> VerifyingKey<P> {
+    /// 公開鍵 P から検証鍵を作成
+    ///
+    /// P が生成点と同じ曲線上にあり、単位元ではなく、n·P = O を満たすことを確認する。
+    pub fn new(generator: &P, order: &BigUint, public_key: P) -> Result<Self, ProtocolError> {
+        let in_subgroup = |point: &P| {
+            point
+                .mul_public(order)
+                .is_ok_and(|point| point.is_identity())
+        };
+        if !is_probable_prime(order)
+            || !in_subgroup(generator)
+            || public_key.is_identity()
+            || !public_key.is_on_same_curve(generator)
+            || !in_subgroup(&public_key)
+        {
+            return Err(ProtocolError::InvalidParameters);
+        }
+        Ok(Self {
+            generator: generator.clone(),
+            order: order.clone(),
+            public_key,
+        })
+    }
+
+    /// 公開鍵を取得
+    pub fn public_key(&self) -> &P {
+        &self.public_key
+    }
+
+    /// 署名を検証
+    ///
+    /// e = H(R || P || m) として sG - eP = R を確認する。
+    pub fn verify(&self, msg: &[u8], signature: &Signature<P>) -> bool {
+        let n = &self.order;
+        let Signature { r, s } = signature;
+        if s >= n || r.is_identity() || !r.is_on_same_curve(&self.generator) {
+            return false;
+        }
+
+        let e = challenge(r, &self.public_key, msg, n);
+        let terms = [
+            (self.generator.clone(), BigInt::from(s.clone())),
+            (self.public_key.clone(), -BigInt::from(e)),
+        ];
+        P::linear_combination(&terms).is_ok_and(|point| point == *r)
+    }
+}
+
+/// チャレンジ e = SHA-256(R || P || m) mod n
+///
+/// 点は `CurvePoint::to_bytes` でエンコードする (Weierstrass形式では圧縮SEC1形式)。
+fn challenge<P: CurvePoint>(r: &P, public_key: &P, msg: &[u8], order: &BigUint) -> BigUint {
+    let hash = Sha256::new()
+        .chain_update(r.to_bytes())
+        .chain_update(public_key.to_bytes())
+        .chain_update(msg)
+        .finalize();
+    bits_to_int(&hash, order) % order
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign_and_verify_toy_curve() {
+        // y² = x³ + 7 over F223, (15, 86) の位数は7
+        let curve = Curve::new(0, 7, 223).unwrap();
+        let generator = curve.point(15, 86).unwrap();
+        let key = SigningKey::new(curve, generator).unwrap();
+
+        let signature = key.sign(b"hello").unwrap();
+        assert!(key.verifying_key().verify(b"hello", &signature));
+    }
+
+    #[test]
+    fn test_sign_with_nonce_matches_definition() {
+        let curve = Curve::new(0, 7, 223).unwrap();
+        let generator = curve.point(15, 86).unwrap();
+        let order = BigUint::from(7u32);
+        let key = SigningKey::from_private_key(&generator, &order, BigUint::from(3u32)).unwrap();
+
+        let nonce = BigUint::from(5u32);
+        let signature = key.sign_with_nonce(b"class", &nonce).unwrap();
+        assert_eq!(signature.r, (generator.clone() * 5).unwrap());
+        let e = challenge(
+            &signature.r,
+            key.verifying_key().public_key(),
+            b"class",
+            &order,
+        );
+        assert_eq!(signature.s, (nonce + e * 3u32) % &order);
+        assert!(key.sign_with_nonce(b"class", &order).is_err());
+    }
+
+    #[test]
+    fn test_deterministic_signatures() {
+        let params = Curve::secp256k1();
+        let key = SigningKey::from_params(&params).unwrap();
+        let signature = key.sign_deterministic(b"message").unwrap();
+        assert_eq!(key.sign_deterministic(b"message").unwrap(), signature);
+        assert_ne!(key.sign_deterministic(b"other").unwrap(), signature);
+        assert!(key.verifying_key().verify(b"message", &signature));
+    }
+
+    #[test]
+    fn test_verify_rejects_tampering() {
+        let params = Curve::p256();
+        let key = SigningKey::from_params(&params).unwrap();
+        let other = SigningKey::from_params(&params).unwrap();
+        let signature = key.sign(b"message").unwrap();
+        let verifying_key = key.verifying_key();
+
+        assert!(verifying_key.verify(b"message", &signature));
+        assert!(!verifying_key.verify(b"massage", &signature));
+        assert!(!other.verifying_key().verify(b"message", &signature));
+
+        let tampered = Signature {
+            r: signature.r.clone(),
+            s: (&signature.s + 1u32) % &params.order,
+        };
+        assert!(!verifying_key.verify(b"message", &tampered));
+        let out_of_range = Signature {
+            r: signature.r.clone(),
+            s: &signature.s + &params.order,
+        };
+        assert!(!verifying_key.verify(b"message", &out_of_range));
+        let infinity = Signature {
+            r: params.curve.infinity_point(),
+            s: signature.s.clone(),
+        };
+        assert!(!verifying_key.verify(b"message", &infinity));
+    }
+
+    #[test]
+    fn test_generic_over_edwards_curve() {
+        // Ed25519 の基点を生成点とするSchnorr署名
+        let generator = crate::protocols::ed25519::base_point();
+        let order = crate::protocols::ed25519::group_order();
+        let key =
+            SigningKey::from_private_key(&generator, &order, BigUint::from(12345u32)).unwrap();
+
+        let signature = key.sign_deterministic(b"edwards").unwrap();
+        assert!(key.verifying_key().verify(b"edwards", &signature));
+        assert!(!key.verifying_key().verify(b"weierstrass", &signature));
+
+        // 位数 L の部分群に含まれない点 (位数2の点 (0, -1)) は公開鍵として拒否する
+        let curve = crate::curve::EdwardsCurve::ed25519();
+        let torsion = curve.point(0, -1).unwrap();
+        assert!(VerifyingKey::new(&generator, &order, torsion).is_err());
+    }
+}
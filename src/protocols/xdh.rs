@@ -0,0 +1,186 @@
+//! X25519 / X448 鍵交換 (RFC 7748)
+//!
+//! 秘密鍵と公開鍵はどちらもバイト列で、公開鍵は u 座標のリトルエンディアン表現。
+
+use super::{clamp_scalar_25519, clamp_scalar_448};
+use crate::curve::MontgomeryCurve;
+use crate::field::FieldElement;
+use num_bigint::BigUint;
+
+/// X25519 の基点 u = 9
+pub const X25519_BASEPOINT: [u8; 32] = {
+    let mut bytes = [0u8; 32];
+    bytes[0] = 9;
+    bytes
+};
+
+/// X448 の基点 u = 5
+pub const X448_BASEPOINT: [u8; 56] = {
+    let mut bytes = [0u8; 56];
+    bytes[0] = 5;
+    bytes
+};
+
+/// X25519 関数
+///
+/// スカラーをクランプし、Curve25519 上で u 座標のMontgomeryラダーを計算する。
+/// 相手の公開鍵が小さな位数の点だと結果が全て0になるので、共有鍵として使う前に確認すること。
+pub fn x25519(scalar: &[u8; 32], u: &[u8; 32]) -> [u8; 32] {
+    let curve = MontgomeryCurve::curve25519();
+    let u = decode_u_coordinate(u, 255, &curve.prime);
+    let result = curve.ladder(&u, &clamp_scalar_25519(scalar));
+    encode_u_coordinate(&result, 32)
+        .try_into()
+        .expect("encoding is 32 bytes")
+}
+
+/// X448 関数
+pub fn x448(scalar: &[u8; 56], u: &[u8; 56]) -> [u8; 56] {
+    let curve = MontgomeryCurve::curve448();
+    let u = decode_u_coordinate(u, 448, &curve.prime);
+    let result = curve.ladder(&u, &clamp_scalar_448(scalar));
+    encode_u_coordinate(&result, 56)
+        .try_into()
+        .expect("encoding is 56 bytes")
+}
+
+/// リトルエンディアンの u 座標をデコード
+///
+/// `bits` を超える上位ビットは無視し、p 以上の値 (非正規形) は p で簡約する。
+pub fn decode_u_coordinate(bytes: &[u8], bits: u64, prime: &BigUint) -> FieldElement {
+    let mut bytes = bytes.to_vec();
+    if !bits.is_multiple_of(8) {
+        if let Some(last) = bytes.last_mut() {
+            *last &= (1u8 << (bits % 8)) - 1;
+        }
+    }
+    FieldElement::new(BigUint::from_bytes_le(&bytes), prime.clone()).expect("prime is valid")
+}
+
+/// u 座標を `len` バイトのリトルエンディアンにエンコード
+pub fn encode_u_coordinate(u: &FieldElement, len: usize) -> Vec<u8> {
+    let mut bytes = u.value().to_bytes_le();
+    bytes.resize(len, 0);
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocols::hex_bytes;
+
+    fn hex_array<const N: usize>(value: &str) -> [u8; N] {
+        hex_bytes(value).try_into().unwrap()
+    }
+
+    #[test]
+    fn test_x25519_vectors() {
+        // RFC 7748 5.2
+        for (scalar, u, expected) in [
+            (
+                "a546e36bf0527c9d3b16154b82465edd62144c0ac1fc5a18506a2244ba449ac4",
+                "e6db6867583030db3594c1a424b15f7c726624ec26b3353b10a903a6d0ab1c4c",
+                "c3da55379de9c6908e94ea4df28d084f32eccf03491c71f754b4075577a28552",
+            ),
+            (
+                "4b66e9d4d1b4673c5ad22691957d6af5c11b6421e0ea01d42ca4169e7918ba0d",
+                "e5210f12786811d3f4b7959d0538ae2c31dbe7106fc03c3efc4cd549c715a493",
+                "95cbde9476e8907d7aade45cb4b873f88b595a68799fa152e6f8f7647aac7957",
+            ),
+        ] {
+            assert_eq!(
+                x25519(&hex_array(scalar), &hex_array(u)),
+                hex_array::<32>(expected)
+            );
+        }
+
+        // 1回の反復: k = u = 9
+        assert_eq!(
+            x25519(&X25519_BASEPOINT, &X25519_BASEPOINT),
+            hex_array::<32>("422c8e7a6227d7bca1350b3e2bb7279f7897b87bb6854b783c60e80311ae3079")
+        );
+    }
+
+    #[test]
+    fn test_x448_vectors() {
+        // RFC 7748 5.2
+        for (scalar, u, expected) in [
+            (
+                "3d262fddf9ec8e88495266fea19a34d28882acef045104d0d1aae121700a779c\
+                 984c24f8cdd78fbff44943eba368f54b29259a4f1c600ad3",
+                "06fce640fa3487bfda5f6cf2d5263f8aad88334cbd07437f020f08f9814dc031\
+                 ddbdc38c19c6da2583fa5429db94ada18aa7a7fb4ef8a086",
+                "ce3e4ff95a60dc6697da1db1d85e6afbdf79b50a2412d7546d5f239fe14fbaad\
+                 eb445fc66a01b0779d98223961111e21766282f73dd96b6f",
+            ),
+            (
+                "203d494428b8399352665ddca42f9de8fef600908e0d461cb021f8c538345dd7\
+                 7c3e4806e25f46d3315c44e0a5b4371282dd2c8d5be3095f",
+                "0fbcc2f993cd56d3305b0b7d9e55d4c1a8fb5dbb52f8e9a1e9b6201b165d0158\
+                 94e56c4d3570bee52fe205e28a78b91cdfbde71ce8d157db",
+                "884a02576239ff7a2f2f63b2db6a9ff37047ac13568e1e30fe63c4a7ad1b3ee3\
+                 a5700df34321d62077e63633c575c1c954514e99da7c179d",
+            ),
+        ] {
+            assert_eq!(
+                x448(&hex_array(scalar), &hex_array(u)),
+                hex_array::<56>(expected)
+            );
+        }
+
+        assert_eq!(
+            x448(&X448_BASEPOINT, &X448_BASEPOINT),
+            hex_array::<56>(
+                "3f482c8a9f19b01e6c46ee9711d9dc14fd4bf67af30765c2ae2b846a4d23a8cd\
+                 0db897086239492caf350b51f833868b9bc2b3bca9cf4113"
+            )
+        );
+    }
+
+    #[test]
+    fn test_x25519_key_exchange() {
+        // RFC 7748 6.1
+        let alice: [u8; 32] =
+            hex_array("77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a");
+        let bob: [u8; 32] =
+            hex_array("5dab087e624a8a4b79e17f8b83800ee66f3bb1292618b6fd1c2f8b27ff88e0eb");
+        let alice_public = x25519(&alice, &X25519_BASEPOINT);
+        let bob_public = x25519(&bob, &X25519_BASEPOINT);
+        assert_eq!(
+            alice_public,
+            hex_array::<32>("8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a")
+        );
+        assert_eq!(
+            bob_public,
+            hex_array::<32>("de9edb7d7b7dc1b4d35b61c2ece435373f8343c85b78674dadfc7e146f882b4f")
+        );
+
+        let shared = x25519(&alice, &bob_public);
+        assert_eq!(shared, x25519(&bob, &alice_public));
+        assert_eq!(
+            shared,
+            hex_array::<32>("4a5d9d5ba4ce2de1728e3bf480350f25e07e21c947d19e3376f09b3c1e161742")
+        );
+    }
+
+    #[test]
+    fn test_u_coordinate_encoding() {
+        let prime = MontgomeryCurve::curve25519().prime;
+        // 最上位ビットは無視する
+        let mut bytes = X25519_BASEPOINT;
+        bytes[31] |= 0x80;
+        assert_eq!(
+            decode_u_coordinate(&bytes, 255, &prime).value(),
+            &BigUint::from(9u32)
+        );
+
+        // 非正規形の p + 1 は 1 に簡約される
+        let mut p_plus_one = (&prime + 1u32).to_bytes_le();
+        p_plus_one.resize(32, 0);
+        let u = decode_u_coordinate(&p_plus_one, 255, &prime);
+        assert_eq!(u.value(), &BigUint::from(1u32));
+        let mut one = vec![0u8; 32];
+        one[0] = 1;
+        assert_eq!(encode_u_coordinate(&u, 32), one);
+    }
+}